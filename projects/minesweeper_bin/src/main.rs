@@ -1,5 +1,8 @@
 use minesweeper_lib::{config::GameConfig, run};
 
 fn main() {
-    run(GameConfig::default());
+    if let Err(error) = run(GameConfig::default()) {
+        eprintln!("Invalid game configuration: {}", error);
+        std::process::exit(1);
+    }
 }
@@ -1,11 +1,15 @@
-use bevy::prelude::*;
 use core::fmt;
 use std::{
     fmt::{Display, Formatter},
     ops::{Add, Sub},
 };
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "bevy",
+    derive(bevy_ecs::component::Component, bevy_reflect::Reflect)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinates {
     /// The x position of the coordinate
     pub x: u16,
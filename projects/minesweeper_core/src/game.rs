@@ -0,0 +1,1075 @@
+use crate::{
+    solver::*, Coordinates, GameSnapshot, GameStatus, Mask, Neighborhood, Outcome, PendingLayout,
+    SnapshotError, Tile, TileMap, TileState, Topology, Wrap,
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    iter,
+};
+
+/// A game in progress: the tile map and what the player uncovered and flagged on it.
+///
+/// The actions `reveal`, `toggle_flag` and `chord` return the state changes they caused. Win and
+/// loss are evaluated once the whole action is applied. Actions can be taken back with `undo` and
+/// applied again with `redo`; the history is not part of a snapshot.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+pub struct Game {
+    tile_map: TileMap,
+    // Stores tile states in left-to-right then bottom-to-top layout
+    tile_states: Vec<TileState>,
+    // The number of flags on every tile, in the layout of the tile states
+    flags: Vec<u8>,
+    covered_count: usize,
+    flagged_count: usize,
+    status: GameStatus,
+    pending_layout: Option<PendingLayout>,
+    seed: u64,
+    generation_attempts: u32,
+    // The outcomes of the actions which can be undone, then of the undone actions, latest last
+    #[cfg_attr(feature = "bevy", reflect(ignore))]
+    done: Vec<Vec<Outcome>>,
+    #[cfg_attr(feature = "bevy", reflect(ignore))]
+    undone: Vec<Vec<Outcome>>,
+}
+
+impl Game {
+    /// Starts a game on a tile map whose bombs are already placed
+    pub fn new(tile_map: TileMap) -> Self {
+        let area = tile_map.grid().area();
+        let tile_count = tile_map.grid().tile_count();
+
+        Self {
+            tile_map,
+            tile_states: vec![TileState::Covered; area],
+            flags: vec![0; area],
+            covered_count: tile_count,
+            flagged_count: 0,
+            status: GameStatus::Playing,
+            pending_layout: None,
+            seed: 0,
+            generation_attempts: 1,
+            done: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    /// Starts a game on a tile map whose bombs are placed from the seed, see
+    /// `TileMap::new_with_bombs_seeded`
+    pub fn new_seeded(width: u16, height: u16, bomb_count: u16, seed: u64) -> Self {
+        Self {
+            seed,
+            ..Self::new(TileMap::new_with_bombs_seeded(
+                width, height, bomb_count, seed,
+            ))
+        }
+    }
+
+    /// Starts a game whose bombs are placed by `pending_layout` when the first tile is revealed.
+    /// The same seed and first revealed tile always produce the same map.
+    pub fn with_pending_layout(
+        width: u16,
+        height: u16,
+        pending_layout: PendingLayout,
+        seed: u64,
+    ) -> Self {
+        Self {
+            pending_layout: Some(pending_layout),
+            seed,
+            ..Self::new(TileMap::empty(width, height))
+        }
+    }
+
+    /// Lays the tiles out with another topology. Must be called before the first action.
+    pub fn with_topology(self, topology: Topology) -> Self {
+        Self {
+            tile_map: self.tile_map.with_topology(topology),
+            ..self
+        }
+    }
+
+    /// Counts the bombs of other neighbors. Must be called before the first action.
+    pub fn with_neighborhood(self, neighborhood: Neighborhood) -> Self {
+        Self {
+            tile_map: self.tile_map.with_neighborhood(neighborhood),
+            ..self
+        }
+    }
+
+    /// Makes edges touch the opposite edge. Must be called before the first action.
+    pub fn with_wrap(self, wrap: Wrap) -> Self {
+        Self {
+            tile_map: self.tile_map.with_wrap(wrap),
+            ..self
+        }
+    }
+
+    /// Shapes the map like the mask. Must be called before the first action. Bombs placed from
+    /// the seed are placed again so that none of them is on a void cell.
+    pub fn with_mask(self, mask: Mask) -> Self {
+        let tile_map = self.tile_map.clone().with_mask(mask);
+        let tile_map = match self.pending_layout {
+            None => tile_map.place_bombs(self.tile_map.bomb_count(), self.seed, &[]),
+            Some(_) => tile_map,
+        };
+
+        Self {
+            covered_count: tile_map.grid().tile_count(),
+            tile_map,
+            ..self
+        }
+    }
+
+    /// Lets a tile hold up to `max_tile_bombs` bombs, at least one, and as many flags. Must be
+    /// called before the first action. Bombs placed from the seed are placed again.
+    ///
+    /// The solver only deduces tiles holding a single bomb: no-guess layouts, hints and mine
+    /// probabilities need a single bomb per tile.
+    pub fn with_max_tile_bombs(self, max_tile_bombs: u8) -> Self {
+        let tile_map = self.tile_map.clone().with_max_tile_bombs(max_tile_bombs);
+        let tile_map = match self.pending_layout {
+            None => tile_map.place_bombs(self.tile_map.bomb_count(), self.seed, &[]),
+            Some(_) => tile_map,
+        };
+
+        Self { tile_map, ..self }
+    }
+
+    pub fn tile_map(&self) -> &TileMap {
+        &self.tile_map
+    }
+
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    /// The seed the bombs were placed from, 0 when they were placed by the caller
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn pending_layout(&self) -> Option<PendingLayout> {
+        self.pending_layout
+    }
+
+    /// The number of layouts generated before the bombs were placed
+    pub fn generation_attempts(&self) -> u32 {
+        self.generation_attempts
+    }
+
+    /// The number of bombs, including the ones of a pending layout
+    pub fn bomb_count(&self) -> u16 {
+        match self.pending_layout {
+            None => self.tile_map.bomb_count(),
+            Some(pending_layout) => pending_layout.bomb_count,
+        }
+    }
+
+    /// The number of tiles which are not revealed, flagged ones included
+    pub fn covered_count(&self) -> usize {
+        self.covered_count
+    }
+
+    /// The number of flags, several flags of a tile included
+    pub fn flagged_count(&self) -> usize {
+        self.flagged_count
+    }
+
+    /// Returns the number of flags on the tile at the coordinates
+    pub fn flag_count(&self, coordinates: Coordinates) -> u8 {
+        if !self.tile_map.in_bounds(coordinates) {
+            return 0;
+        }
+
+        self.flags[self.index(coordinates)]
+    }
+
+    /// Returns the state of the tile at the coordinates, `None` when they are out of bounds
+    pub fn tile_state(&self, coordinates: Coordinates) -> Option<TileState> {
+        if !self.tile_map.in_bounds(coordinates) {
+            return None;
+        }
+
+        Some(self.tile_states[self.index(coordinates)])
+    }
+
+    /// Returns true if the tile at the coordinates is not revealed; otherwise false
+    pub fn is_covered(&self, coordinates: Coordinates) -> bool {
+        self.tile_state(coordinates)
+            .is_some_and(|tile_state| tile_state != TileState::Revealed)
+    }
+
+    /// Returns true if the tile at the coordinates is flagged; otherwise false
+    pub fn is_flagged(&self, coordinates: Coordinates) -> bool {
+        self.tile_state(coordinates) == Some(TileState::Flagged)
+    }
+
+    /// Returns the coordinates of every flagged tile in left-to-right then bottom-to-top order
+    pub fn iter_flagged(&self) -> impl Iterator<Item = Coordinates> + '_ {
+        self.iter_coordinates()
+            .filter(|coordinates| self.is_flagged(*coordinates))
+    }
+
+    /// Reveals a covered tile which is not flagged, opening the area around it when it has no
+    /// neighbor bomb. The first reveal of a pending layout places the bombs.
+    pub fn reveal(&mut self, coordinates: Coordinates) -> Vec<Outcome> {
+        let mut outcomes = Vec::new();
+        if self.status != GameStatus::Playing
+            || self.tile_state(coordinates) != Some(TileState::Covered)
+        {
+            return outcomes;
+        }
+
+        if let Some(pending_layout) = self.pending_layout.take() {
+            outcomes.push(self.lay_out(pending_layout, coordinates));
+        }
+
+        self.reveal_tiles(&[coordinates], &mut outcomes);
+        self.finish_action(&mut outcomes);
+        self.remember(outcomes)
+    }
+
+    /// Flags a covered tile, adds a flag to it while it can hold more bombs, or removes its flags
+    pub fn toggle_flag(&mut self, coordinates: Coordinates) -> Vec<Outcome> {
+        if self.status != GameStatus::Playing {
+            return Vec::new();
+        }
+
+        match self.tile_state(coordinates) {
+            Some(TileState::Covered) => {
+                self.add_flag(coordinates);
+                self.remember(vec![Outcome::Flagged(coordinates)])
+            }
+            Some(TileState::Flagged)
+                if self.flag_count(coordinates) < self.tile_map.max_tile_bombs() =>
+            {
+                self.add_flag(coordinates);
+                self.remember(vec![Outcome::Flagged(coordinates)])
+            }
+            Some(TileState::Flagged) => {
+                let mut outcomes = Vec::new();
+                self.remove_flags(coordinates, &mut outcomes);
+                self.remember(outcomes)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Reveals the covered neighbors of a revealed number which are not flagged, when its
+    /// neighbors hold as many flags as the number shows
+    pub fn chord(&mut self, coordinates: Coordinates) -> Vec<Outcome> {
+        let mut outcomes = Vec::new();
+        if self.status != GameStatus::Playing {
+            return outcomes;
+        }
+
+        let tiles_to_chord = self.tiles_to_chord(coordinates);
+        if tiles_to_chord.is_empty() {
+            return outcomes;
+        }
+
+        self.reveal_tiles(&tiles_to_chord, &mut outcomes);
+        self.finish_action(&mut outcomes);
+        self.remember(outcomes)
+    }
+
+    /// Reveals a covered tile or chords a revealed one
+    pub fn trigger(&mut self, coordinates: Coordinates) -> Vec<Outcome> {
+        match self.tile_state(coordinates) {
+            Some(TileState::Covered) => self.reveal(coordinates),
+            Some(TileState::Revealed) => self.chord(coordinates),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns true if an action can be undone; otherwise false
+    pub fn can_undo(&self) -> bool {
+        !self.done.is_empty()
+    }
+
+    /// Returns true if an undone action can be applied again; otherwise false
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// Takes back the last action, even one which ended the game. Revealed tiles are covered
+    /// again, flags are restored and an explosion is defused. A layout placed by the action is
+    /// kept, so its bombs do not move.
+    pub fn undo(&mut self) -> Vec<Outcome> {
+        let Some(outcomes) = self.done.pop() else {
+            return Vec::new();
+        };
+
+        let mut reverted = Vec::new();
+        for outcome in outcomes.iter().rev() {
+            match *outcome {
+                Outcome::Flagged(coordinates) => {
+                    self.remove_flag(coordinates);
+                    reverted.push(Outcome::Unflagged(coordinates));
+                }
+                Outcome::Unflagged(coordinates) => {
+                    self.add_flag(coordinates);
+                    reverted.push(Outcome::Flagged(coordinates));
+                }
+                Outcome::Revealed { coordinates, .. } => {
+                    self.set_covered(coordinates);
+                    reverted.push(Outcome::Covered(coordinates));
+                }
+                Outcome::Exploded(coordinates) => {
+                    self.status = GameStatus::Playing;
+                    reverted.push(Outcome::Defused(coordinates));
+                }
+                Outcome::Won => self.status = GameStatus::Playing,
+                Outcome::LaidOut { .. } | Outcome::Covered(_) | Outcome::Defused(_) => {}
+            }
+        }
+
+        self.undone.push(outcomes);
+        reverted
+    }
+
+    /// Applies the last undone action again, returning the outcomes it had. The layout placed by
+    /// the action was kept, it is not placed again.
+    pub fn redo(&mut self) -> Vec<Outcome> {
+        let Some(outcomes) = self.undone.pop() else {
+            return Vec::new();
+        };
+
+        for outcome in &outcomes {
+            match *outcome {
+                Outcome::Flagged(coordinates) => self.add_flag(coordinates),
+                Outcome::Unflagged(coordinates) => self.remove_flag(coordinates),
+                Outcome::Revealed { coordinates, .. } => self.set_revealed(coordinates),
+                Outcome::Exploded(_) => self.status = GameStatus::Lost,
+                Outcome::Won => self.status = GameStatus::Won,
+                Outcome::LaidOut { .. } | Outcome::Covered(_) | Outcome::Defused(_) => {}
+            }
+        }
+
+        let redone = outcomes
+            .iter()
+            .filter(|outcome| !matches!(outcome, Outcome::LaidOut { .. }))
+            .copied()
+            .collect();
+        self.done.push(outcomes);
+        redone
+    }
+
+    /// Returns the tiles `chord` would reveal
+    pub fn tiles_to_chord(&self, coordinates: Coordinates) -> Vec<Coordinates> {
+        if self.tile_state(coordinates) != Some(TileState::Revealed) {
+            return Vec::new();
+        }
+
+        let number = match self.tile_at(coordinates) {
+            Tile::BombNeighbor(v) => v,
+            _ => return Vec::new(),
+        };
+
+        let flagged_neighbors: usize = self
+            .iter_neighbors(coordinates)
+            .map(|neighbor| self.flag_count(neighbor) as usize)
+            .sum();
+        if flagged_neighbors != number as usize {
+            return Vec::new();
+        }
+
+        self.iter_neighbors(coordinates)
+            .filter(|neighbor| self.tile_state(*neighbor) == Some(TileState::Covered))
+            .collect()
+    }
+
+    /// Captures the state of the game
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            width: self.tile_map.width(),
+            height: self.tile_map.height(),
+            topology: self.tile_map.topology(),
+            wrap: self.tile_map.wrap(),
+            neighborhood: self.tile_map.neighborhood().clone(),
+            mask: self.tile_map.mask().cloned(),
+            max_tile_bombs: self.tile_map.max_tile_bombs(),
+            bombs: self
+                .iter_coordinates()
+                .flat_map(|coordinates| {
+                    iter::repeat_n(coordinates, self.tile_map.bombs_at(coordinates) as usize)
+                })
+                .collect(),
+            revealed: self
+                .iter_coordinates()
+                .filter(|coordinates| self.tile_state(*coordinates) == Some(TileState::Revealed))
+                .collect(),
+            flagged: self
+                .iter_coordinates()
+                .flat_map(|coordinates| {
+                    iter::repeat_n(coordinates, self.flag_count(coordinates) as usize)
+                })
+                .collect(),
+            pending_layout: self.pending_layout,
+            seed: self.seed,
+            generation_attempts: self.generation_attempts,
+        }
+    }
+
+    /// Restores a game from a snapshot, checking that it describes a game which could have been
+    /// played. The status is computed again from the revealed tiles.
+    pub fn from_snapshot(snapshot: &GameSnapshot) -> Result<Self, SnapshotError> {
+        if let Some(mask) = &snapshot.mask {
+            if !mask.fits(snapshot.width, snapshot.height) {
+                return Err(SnapshotError::InvalidMask);
+            }
+        }
+
+        let grid = snapshot.grid();
+        let tile_count = grid.tile_count();
+        let max_tile_bombs = snapshot.max_tile_bombs.max(1);
+        if tile_count == 0 {
+            return Err(SnapshotError::EmptyMap);
+        }
+
+        let bomb_count = match snapshot.pending_layout {
+            Some(pending_layout) => {
+                if !snapshot.bombs.is_empty() || !snapshot.revealed.is_empty() {
+                    return Err(SnapshotError::PendingLayoutStarted);
+                }
+                pending_layout.bomb_count as usize
+            }
+            None => snapshot.bombs.len(),
+        };
+        if bomb_count > (tile_count - 1) * max_tile_bombs as usize {
+            return Err(SnapshotError::TooManyBombs {
+                bomb_count,
+                tile_count,
+            });
+        }
+
+        // A tile is listed once per bomb or flag it holds
+        let check_set = |set: &[Coordinates], max_count: u8| {
+            let mut seen = HashMap::with_capacity(set.len());
+            for coordinates in set {
+                if !grid.in_bounds(*coordinates) {
+                    return Err(SnapshotError::OutOfBounds(*coordinates));
+                }
+                let count = seen.entry(*coordinates).or_insert(0);
+                *count += 1;
+                if *count > max_count {
+                    return Err(SnapshotError::Duplicate(*coordinates));
+                }
+            }
+            Ok(seen.into_keys().collect::<HashSet<_>>())
+        };
+        check_set(&snapshot.bombs, max_tile_bombs)?;
+        let revealed = check_set(&snapshot.revealed, 1)?;
+        check_set(&snapshot.flagged, max_tile_bombs)?;
+        if let Some(coordinates) = snapshot.flagged.iter().find(|c| revealed.contains(c)) {
+            return Err(SnapshotError::FlaggedRevealed(*coordinates));
+        }
+
+        let tile_map = TileMap::empty_grid(grid)
+            .with_max_tile_bombs(max_tile_bombs)
+            .place_bombs_at(&snapshot.bombs);
+        let mut game = Self {
+            pending_layout: snapshot.pending_layout,
+            seed: snapshot.seed,
+            generation_attempts: snapshot.generation_attempts,
+            ..Self::new(tile_map)
+        };
+        for coordinates in &snapshot.revealed {
+            game.set_revealed(*coordinates);
+        }
+        for coordinates in &snapshot.flagged {
+            game.add_flag(*coordinates);
+        }
+
+        if snapshot
+            .revealed
+            .iter()
+            .any(|coordinates| game.tile_map.is_bomb_at(*coordinates))
+        {
+            game.status = GameStatus::Lost;
+        } else if game.pending_layout.is_none()
+            && game.covered_count == game.tile_map.bomb_tile_count()
+        {
+            game.status = GameStatus::Won;
+        }
+
+        Ok(game)
+    }
+
+    /// Places the bombs of a pending layout so that `start`, and its neighbors for an opening, are
+    /// safe
+    fn lay_out(&mut self, pending_layout: PendingLayout, start: Coordinates) -> Outcome {
+        let mut safe_tiles = vec![start];
+        if pending_layout.opening {
+            safe_tiles.extend(self.iter_neighbors(start));
+
+            // Fall back to a single safe tile when the opening leaves no room for the bombs
+            let room = self
+                .tile_map
+                .grid()
+                .tile_count()
+                .saturating_sub(safe_tiles.len())
+                * self.tile_map.max_tile_bombs() as usize;
+            if room < pending_layout.bomb_count as usize {
+                safe_tiles.truncate(1);
+            }
+        }
+
+        let (tile_map, attempts, solvable) = match pending_layout.no_guess_max_attempts {
+            None => {
+                let tile_map = self.tile_map.clone().place_bombs(
+                    pending_layout.bomb_count,
+                    self.seed,
+                    &safe_tiles,
+                );
+                (tile_map, 1, false)
+            }
+            Some(max_attempts) => {
+                let layout = NoGuessLayout::generate(
+                    &self.tile_map,
+                    pending_layout.bomb_count,
+                    self.seed,
+                    start,
+                    &safe_tiles,
+                    max_attempts,
+                );
+                (layout.tile_map, layout.attempts, layout.solvable)
+            }
+        };
+
+        self.tile_map = tile_map;
+        self.generation_attempts = attempts;
+        Outcome::LaidOut { attempts, solvable }
+    }
+
+    /// Reveals the tiles and floods the openings they start in one breadth-first pass, so tiles are
+    /// revealed by increasing distance. Flags inside an opening are removed.
+    fn reveal_tiles(&mut self, starts: &[Coordinates], outcomes: &mut Vec<Outcome>) {
+        let mut queue = VecDeque::new();
+        for start in starts {
+            if self.tile_state(*start) == Some(TileState::Covered) {
+                self.set_revealed(*start);
+                queue.push_back((*start, 0));
+            }
+        }
+
+        while let Some((coordinates, distance)) = queue.pop_front() {
+            outcomes.push(Outcome::Revealed {
+                coordinates,
+                distance,
+            });
+
+            match self.tile_at(coordinates) {
+                Tile::Bomb(_) => outcomes.push(Outcome::Exploded(coordinates)),
+                Tile::BombNeighbor(_) | Tile::Void => {}
+                Tile::Empty => {
+                    let neighbors: Vec<Coordinates> = self.iter_neighbors(coordinates).collect();
+                    for neighbor in neighbors {
+                        match self.tile_state(neighbor) {
+                            Some(TileState::Covered) => {}
+                            Some(TileState::Flagged) => self.remove_flags(neighbor, outcomes),
+                            _ => continue,
+                        }
+                        self.set_revealed(neighbor);
+                        queue.push_back((neighbor, distance + 1));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Ends the game when the action exploded a bomb or revealed the last safe tile
+    fn finish_action(&mut self, outcomes: &mut Vec<Outcome>) {
+        if outcomes
+            .iter()
+            .any(|outcome| matches!(outcome, Outcome::Exploded(_)))
+        {
+            self.status = GameStatus::Lost;
+            return;
+        }
+
+        if self.covered_count != self.tile_map.bomb_tile_count() {
+            return;
+        }
+
+        // Every covered tile holds bombs, it gets a flag for each of them
+        let bombs: Vec<Coordinates> = self.iter_coordinates().collect();
+        for coordinates in bombs {
+            while self.flag_count(coordinates) < self.tile_map.bombs_at(coordinates) {
+                self.add_flag(coordinates);
+                outcomes.push(Outcome::Flagged(coordinates));
+            }
+        }
+
+        self.status = GameStatus::Won;
+        outcomes.push(Outcome::Won);
+    }
+
+    fn add_flag(&mut self, coordinates: Coordinates) {
+        let index = self.index(coordinates);
+        self.tile_states[index] = TileState::Flagged;
+        self.flags[index] += 1;
+        self.flagged_count += 1;
+    }
+
+    fn remove_flag(&mut self, coordinates: Coordinates) {
+        let index = self.index(coordinates);
+        self.flags[index] -= 1;
+        if self.flags[index] == 0 {
+            self.tile_states[index] = TileState::Covered;
+        }
+        self.flagged_count -= 1;
+    }
+
+    /// Removes every flag of the tile, one outcome per flag
+    fn remove_flags(&mut self, coordinates: Coordinates, outcomes: &mut Vec<Outcome>) {
+        while self.flag_count(coordinates) > 0 {
+            self.remove_flag(coordinates);
+            outcomes.push(Outcome::Unflagged(coordinates));
+        }
+    }
+
+    /// Keeps the outcomes of an action so that it can be undone, forgetting the undone actions
+    fn remember(&mut self, outcomes: Vec<Outcome>) -> Vec<Outcome> {
+        if !outcomes.is_empty() {
+            self.done.push(outcomes.clone());
+            self.undone.clear();
+        }
+        outcomes
+    }
+
+    fn set_covered(&mut self, coordinates: Coordinates) {
+        let index = self.index(coordinates);
+        self.tile_states[index] = TileState::Covered;
+        self.covered_count += 1;
+    }
+
+    fn set_revealed(&mut self, coordinates: Coordinates) {
+        let index = self.index(coordinates);
+        self.tile_states[index] = TileState::Revealed;
+        self.covered_count -= 1;
+    }
+
+    fn tile_at(&self, coordinates: Coordinates) -> Tile {
+        self.tile_map.map()[coordinates.y as usize][coordinates.x as usize]
+    }
+
+    fn iter_coordinates(&self) -> impl Iterator<Item = Coordinates> + '_ {
+        self.tile_map.iter_coordinates()
+    }
+
+    fn iter_neighbors(&self, coordinates: Coordinates) -> impl Iterator<Item = Coordinates> + '_ {
+        self.tile_map.iter_neighbors(coordinates)
+    }
+
+    fn index(&self, coordinates: Coordinates) -> usize {
+        self.tile_map.grid().index(coordinates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(x: u16, y: u16) -> Coordinates {
+        Coordinates { x, y }
+    }
+
+    /// A 3x3 game with a single bomb in the bottom left corner
+    fn corner_bomb_game() -> Game {
+        Game::new(TileMap::new_with_bombs_at(3, 3, &[c(0, 0)]))
+    }
+
+    fn revealed(outcomes: &[Outcome]) -> Vec<Coordinates> {
+        outcomes
+            .iter()
+            .filter_map(|outcome| match outcome {
+                Outcome::Revealed { coordinates, .. } => Some(*coordinates),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reveal_number_reveals_a_single_tile() {
+        let mut game = corner_bomb_game();
+
+        assert_eq!(
+            game.reveal(c(1, 1)),
+            vec![Outcome::Revealed {
+                coordinates: c(1, 1),
+                distance: 0
+            }]
+        );
+        assert_eq!(game.status(), GameStatus::Playing);
+        assert_eq!(game.covered_count(), 8);
+
+        // Revealing it again does nothing
+        assert!(game.reveal(c(1, 1)).is_empty());
+    }
+
+    #[test]
+    fn reveal_empty_tile_opens_the_area_and_wins() {
+        let mut game = corner_bomb_game();
+        let outcomes = game.reveal(c(2, 2));
+
+        assert_eq!(outcomes.len(), 10);
+        assert_eq!(
+            outcomes[0],
+            Outcome::Revealed {
+                coordinates: c(2, 2),
+                distance: 0
+            }
+        );
+        assert!(outcomes.contains(&Outcome::Revealed {
+            coordinates: c(1, 1),
+            distance: 1
+        }));
+        assert!(!revealed(&outcomes).contains(&c(0, 0)));
+        assert_eq!(outcomes[8..], [Outcome::Flagged(c(0, 0)), Outcome::Won]);
+        assert_eq!(game.status(), GameStatus::Won);
+        assert!(game.is_flagged(c(0, 0)));
+    }
+
+    #[test]
+    fn reveal_bomb_loses() {
+        let mut game = corner_bomb_game();
+
+        assert_eq!(
+            game.reveal(c(0, 0)),
+            vec![
+                Outcome::Revealed {
+                    coordinates: c(0, 0),
+                    distance: 0
+                },
+                Outcome::Exploded(c(0, 0))
+            ]
+        );
+        assert_eq!(game.status(), GameStatus::Lost);
+
+        // The game does not accept actions once over
+        assert!(game.reveal(c(2, 2)).is_empty());
+        assert!(game.toggle_flag(c(2, 2)).is_empty());
+    }
+
+    #[test]
+    fn opening_removes_the_flags_in_its_way() {
+        let mut game = corner_bomb_game();
+        game.toggle_flag(c(1, 2));
+        let outcomes = game.reveal(c(2, 2));
+
+        assert!(outcomes.contains(&Outcome::Unflagged(c(1, 2))));
+        assert!(revealed(&outcomes).contains(&c(1, 2)));
+        assert_eq!(game.flagged_count(), 1);
+    }
+
+    #[test]
+    fn toggle_flag_flags_and_unflags_covered_tiles() {
+        let mut game = corner_bomb_game();
+
+        assert_eq!(game.toggle_flag(c(0, 0)), vec![Outcome::Flagged(c(0, 0))]);
+        assert!(game.is_flagged(c(0, 0)));
+        assert_eq!(game.flagged_count(), 1);
+
+        // A flagged tile can not be revealed
+        assert!(game.reveal(c(0, 0)).is_empty());
+
+        assert_eq!(game.toggle_flag(c(0, 0)), vec![Outcome::Unflagged(c(0, 0))]);
+        assert!(!game.is_flagged(c(0, 0)));
+        assert_eq!(game.flagged_count(), 0);
+
+        // A revealed tile can not be flagged
+        game.reveal(c(1, 1));
+        assert!(game.toggle_flag(c(1, 1)).is_empty());
+    }
+
+    #[test]
+    fn toggle_flag_stacks_flags_up_to_the_max_tile_bombs() {
+        let mut game = Game::new(
+            TileMap::empty(3, 3)
+                .with_max_tile_bombs(2)
+                .place_bombs_at(&[c(0, 0), c(0, 0)]),
+        );
+
+        assert_eq!(game.toggle_flag(c(0, 0)), vec![Outcome::Flagged(c(0, 0))]);
+        assert_eq!(game.toggle_flag(c(0, 0)), vec![Outcome::Flagged(c(0, 0))]);
+        assert_eq!(game.flag_count(c(0, 0)), 2);
+        assert_eq!(
+            game.toggle_flag(c(0, 0)),
+            vec![Outcome::Unflagged(c(0, 0)), Outcome::Unflagged(c(0, 0))]
+        );
+        assert_eq!(game.flag_count(c(0, 0)), 0);
+    }
+
+    #[test]
+    fn win_needs_the_tiles_holding_bombs_covered_not_the_bomb_count() {
+        // Two bombs on a single tile leave two safe tiles to reveal
+        let mut game = Game::new(
+            TileMap::empty(3, 1)
+                .with_max_tile_bombs(2)
+                .place_bombs_at(&[c(0, 0), c(0, 0)]),
+        );
+        game.reveal(c(1, 0));
+        assert_eq!(game.status(), GameStatus::Playing);
+
+        let outcomes = game.reveal(c(2, 0));
+        assert_eq!(game.covered_count(), 1);
+        assert_eq!(game.status(), GameStatus::Won);
+        assert!(outcomes.ends_with(&[
+            Outcome::Flagged(c(0, 0)),
+            Outcome::Flagged(c(0, 0)),
+            Outcome::Won
+        ]));
+        assert_eq!(game.flag_count(c(0, 0)), 2);
+    }
+
+    #[test]
+    fn chord_needs_as_many_flags_as_the_number() {
+        let mut game = corner_bomb_game();
+        game.reveal(c(1, 1));
+        assert!(game.chord(c(1, 1)).is_empty());
+
+        game.toggle_flag(c(0, 0));
+        let outcomes = game.chord(c(1, 1));
+
+        let mut chorded = revealed(&outcomes);
+        chorded.sort();
+        assert_eq!(
+            chorded,
+            [
+                c(0, 1),
+                c(0, 2),
+                c(1, 0),
+                c(1, 2),
+                c(2, 0),
+                c(2, 1),
+                c(2, 2)
+            ]
+        );
+        assert_eq!(outcomes.last(), Some(&Outcome::Won));
+    }
+
+    #[test]
+    fn chord_with_a_wrong_flag_explodes() {
+        let mut game = corner_bomb_game();
+        game.reveal(c(1, 1));
+        game.toggle_flag(c(2, 2));
+        let outcomes = game.chord(c(1, 1));
+
+        assert!(outcomes.contains(&Outcome::Exploded(c(0, 0))));
+        assert_eq!(game.status(), GameStatus::Lost);
+    }
+
+    #[test]
+    fn undo_then_redo_restores_the_exact_board() {
+        let mut game = corner_bomb_game();
+        let mut snapshots = vec![game.snapshot()];
+        game.reveal(c(1, 1));
+        snapshots.push(game.snapshot());
+        game.toggle_flag(c(2, 2));
+        snapshots.push(game.snapshot());
+        game.chord(c(1, 1));
+        snapshots.push(game.snapshot());
+        assert_eq!(game.status(), GameStatus::Lost);
+
+        for snapshot in snapshots.iter().rev().skip(1) {
+            assert!(!game.undo().is_empty());
+            assert_eq!(&game.snapshot(), snapshot);
+        }
+        assert!(!game.can_undo());
+        assert_eq!(game.status(), GameStatus::Playing);
+        assert_eq!(game.covered_count(), 9);
+
+        for snapshot in snapshots.iter().skip(1) {
+            assert!(!game.redo().is_empty());
+            assert_eq!(&game.snapshot(), snapshot);
+        }
+        assert!(!game.can_redo());
+        assert_eq!(game.status(), GameStatus::Lost);
+    }
+
+    #[test]
+    fn undo_reverts_the_outcomes_of_an_action() {
+        let mut game = corner_bomb_game();
+        game.reveal(c(1, 1));
+        game.toggle_flag(c(2, 2));
+        let outcomes = game.chord(c(1, 1));
+        let reverted = game.undo();
+
+        // The chord exploded the bomb and opened the area removing the wrong flag
+        assert!(reverted.contains(&Outcome::Defused(c(0, 0))));
+        assert!(reverted.contains(&Outcome::Covered(c(0, 0))));
+        assert!(reverted.contains(&Outcome::Flagged(c(2, 2))));
+        assert!(!reverted.contains(&Outcome::Covered(c(1, 1))));
+        assert!(game.is_flagged(c(2, 2)));
+        assert_eq!(game.redo(), outcomes);
+    }
+
+    #[test]
+    fn undo_of_the_first_reveal_keeps_the_layout() {
+        let pending_layout = PendingLayout {
+            bomb_count: 1,
+            opening: true,
+            no_guess_max_attempts: None,
+        };
+        let mut game = Game::with_pending_layout(3, 3, pending_layout, 3);
+        let outcomes = game.reveal(c(1, 1));
+        let snapshot = game.snapshot();
+        assert!(matches!(outcomes[0], Outcome::LaidOut { .. }));
+
+        game.undo();
+        assert_eq!(game.covered_count(), 9);
+        assert_eq!(game.pending_layout(), None);
+        assert_eq!(game.snapshot().bombs, snapshot.bombs);
+
+        // The layout is not placed again
+        assert_eq!(game.redo(), outcomes[1..]);
+        assert_eq!(game.snapshot(), snapshot);
+    }
+
+    #[test]
+    fn new_action_forgets_the_undone_ones() {
+        let mut game = corner_bomb_game();
+        game.toggle_flag(c(0, 0));
+        game.undo();
+        assert!(game.can_redo());
+
+        game.toggle_flag(c(2, 2));
+        assert!(!game.can_redo());
+        assert!(game.redo().is_empty());
+        assert!(!game.is_flagged(c(0, 0)));
+    }
+
+    #[test]
+    fn same_seed_lays_out_the_same_game() {
+        let bombs = |game: &Game| -> Vec<Coordinates> {
+            game.tile_map()
+                .iter_coordinates()
+                .filter(|coordinates| game.tile_map().is_bomb_at(*coordinates))
+                .collect()
+        };
+        assert_eq!(
+            bombs(&Game::new_seeded(16, 16, 40, 7)),
+            bombs(&Game::new_seeded(16, 16, 40, 7))
+        );
+        assert_ne!(
+            bombs(&Game::new_seeded(16, 16, 40, 7)),
+            bombs(&Game::new_seeded(16, 16, 40, 8))
+        );
+
+        // A pending layout only depends on the seed and the first reveal
+        let pending_layout = PendingLayout {
+            bomb_count: 40,
+            opening: true,
+            no_guess_max_attempts: Some(100),
+        };
+        let laid_out = |seed| {
+            let mut game = Game::with_pending_layout(16, 16, pending_layout, seed);
+            game.reveal(c(8, 8));
+            bombs(&game)
+        };
+        assert_eq!(laid_out(7), laid_out(7));
+        assert_ne!(laid_out(7), laid_out(8));
+    }
+
+    #[test]
+    fn first_reveal_of_a_pending_layout_is_safe() {
+        let pending_layout = PendingLayout {
+            bomb_count: 10,
+            opening: true,
+            no_guess_max_attempts: None,
+        };
+
+        for seed in 0..100 {
+            for start in [c(0, 0), c(4, 4), c(8, 3)] {
+                let mut game = Game::with_pending_layout(9, 9, pending_layout, seed);
+                let outcomes = game.reveal(start);
+
+                assert!(matches!(outcomes[0], Outcome::LaidOut { .. }));
+                assert_eq!(game.pending_layout(), None);
+                assert_eq!(game.tile_map().bomb_count(), 10);
+                assert_eq!(game.status(), GameStatus::Playing);
+                assert_eq!(
+                    game.tile_map().map()[start.y as usize][start.x as usize],
+                    Tile::Empty
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn first_reveal_falls_back_to_a_single_safe_tile() {
+        // 8 bombs leave no room for an opening on a 3x3 map
+        let pending_layout = PendingLayout {
+            bomb_count: 8,
+            opening: true,
+            no_guess_max_attempts: None,
+        };
+        let mut game = Game::with_pending_layout(3, 3, pending_layout, 7);
+        game.reveal(c(1, 1));
+
+        assert!(!game.tile_map().is_bomb_at(c(1, 1)));
+        assert_eq!(game.status(), GameStatus::Won);
+    }
+
+    #[test]
+    fn snapshot_round_trips() {
+        let mut game = corner_bomb_game();
+        game.reveal(c(1, 1));
+        game.toggle_flag(c(0, 0));
+        let snapshot = game.snapshot();
+        let restored = Game::from_snapshot(&snapshot).unwrap();
+
+        assert_eq!(restored.snapshot(), snapshot);
+        assert_eq!(restored.status(), GameStatus::Playing);
+        assert_eq!(restored.covered_count(), 8);
+        assert_eq!(restored.flagged_count(), 1);
+    }
+
+    #[test]
+    fn from_snapshot_rejects_impossible_games() {
+        let mut game = corner_bomb_game();
+        game.reveal(c(1, 1));
+        let snapshot = game.snapshot();
+        let rejects = |change: &dyn Fn(&mut GameSnapshot), error: SnapshotError| {
+            let mut snapshot = snapshot.clone();
+            change(&mut snapshot);
+            assert_eq!(Game::from_snapshot(&snapshot).unwrap_err(), error);
+        };
+
+        rejects(&|snapshot| snapshot.width = 0, SnapshotError::EmptyMap);
+        rejects(
+            &|snapshot| snapshot.mask = Some(Mask::from_ascii("##\n##")),
+            SnapshotError::InvalidMask,
+        );
+        rejects(
+            &|snapshot| snapshot.bombs = snapshot.grid().iter_coordinates().collect(),
+            SnapshotError::TooManyBombs {
+                bomb_count: 9,
+                tile_count: 9,
+            },
+        );
+        rejects(
+            &|snapshot| snapshot.revealed.push(c(3, 0)),
+            SnapshotError::OutOfBounds(c(3, 0)),
+        );
+        rejects(
+            &|snapshot| snapshot.bombs.push(c(0, 0)),
+            SnapshotError::Duplicate(c(0, 0)),
+        );
+        rejects(
+            &|snapshot| snapshot.flagged.push(c(1, 1)),
+            SnapshotError::FlaggedRevealed(c(1, 1)),
+        );
+        rejects(
+            &|snapshot| {
+                snapshot.pending_layout = Some(PendingLayout {
+                    bomb_count: 1,
+                    opening: false,
+                    no_guess_max_attempts: None,
+                })
+            },
+            SnapshotError::PendingLayoutStarted,
+        );
+    }
+}
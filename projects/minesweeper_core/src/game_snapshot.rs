@@ -0,0 +1,63 @@
+use crate::{Coordinates, Grid, Mask, Neighborhood, PendingLayout, Topology, Wrap};
+
+/// The state of a `Game` as plain data, to save it and restore it later with
+/// `Game::from_snapshot`
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameSnapshot {
+    /// The number of tiles in a row of the map
+    pub width: u16,
+    /// The number of rows of the map
+    pub height: u16,
+    /// How the tiles are laid out
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub topology: Topology,
+    /// Which edges touch the opposite edge
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub wrap: Wrap,
+    /// Which tiles are neighbors
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub neighborhood: Neighborhood,
+    /// Which cells of the map are tiles, every cell when `None`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mask: Option<Mask>,
+    /// The most bombs a tile can hold
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "GameSnapshot::default_max_tile_bombs")
+    )]
+    pub max_tile_bombs: u8,
+    /// The bombs of the map, a tile listed once per bomb it holds, empty while the layout is
+    /// pending
+    pub bombs: Vec<Coordinates>,
+    /// The revealed tiles
+    pub revealed: Vec<Coordinates>,
+    /// The flagged tiles, a tile listed once per flag
+    pub flagged: Vec<Coordinates>,
+    /// How the bombs are placed on the first reveal, if they are not placed yet
+    pub pending_layout: Option<PendingLayout>,
+    /// The seed used to place the bombs
+    pub seed: u64,
+    /// The number of layouts generated before the bombs were placed
+    pub generation_attempts: u32,
+}
+
+impl GameSnapshot {
+    /// A tile held a single bomb before the snapshots said how many it can hold
+    #[cfg(feature = "serde")]
+    fn default_max_tile_bombs() -> u8 {
+        1
+    }
+
+    /// Returns the grid of the map
+    pub fn grid(&self) -> Grid {
+        Grid {
+            width: self.width,
+            height: self.height,
+            topology: self.topology,
+            wrap: self.wrap,
+            neighborhood: self.neighborhood.clone(),
+            mask: self.mask.clone(),
+        }
+    }
+}
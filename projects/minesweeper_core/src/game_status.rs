@@ -0,0 +1,9 @@
+/// The outcome of a game, actions are only applied while `Playing`
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+pub enum GameStatus {
+    #[default]
+    Playing,
+    Won,
+    Lost,
+}
@@ -0,0 +1,245 @@
+use crate::{Coordinates, Mask, Neighborhood, Topology, Wrap};
+
+/// The tiles of a map and which of them are neighbors, whatever they hold
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+pub struct Grid {
+    /// The number of tiles in a row
+    pub width: u16,
+    /// The number of rows
+    pub height: u16,
+    pub topology: Topology,
+    /// Which edges touch the opposite edge. An axis not longer than twice the reach of the
+    /// neighborhood does not wrap, a tile could be the neighbor of another one twice, neither do
+    /// hex rows of an odd height which could not alternate across the edge.
+    pub wrap: Wrap,
+    pub neighborhood: Neighborhood,
+    /// Which cells of the rectangle are tiles, every cell when `None`. The cells outside of the
+    /// mask are void.
+    pub mask: Option<Mask>,
+}
+
+impl Grid {
+    /// A grid of square tiles which does not wrap
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            ..Self::default()
+        }
+    }
+
+    /// The number of tiles, void cells excluded
+    pub fn tile_count(&self) -> usize {
+        match &self.mask {
+            None => self.area(),
+            Some(_) => self.iter_coordinates().count(),
+        }
+    }
+
+    /// The number of cells of the rectangle, void cells included, which is one more than the
+    /// largest `index`
+    pub fn area(&self) -> usize {
+        self.width as usize * self.height as usize
+    }
+
+    /// Returns true if the coordinates are a tile of the grid; otherwise false, on void cells too
+    pub fn in_bounds(&self, coordinates: Coordinates) -> bool {
+        coordinates.x < self.width
+            && coordinates.y < self.height
+            && self
+                .mask
+                .as_ref()
+                .is_none_or(|mask| mask.contains(coordinates))
+    }
+
+    /// Returns every coordinates of the tiles of the grid in left-to-right then bottom-to-top
+    /// order
+    pub fn iter_coordinates(&self) -> impl Iterator<Item = Coordinates> + '_ {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Coordinates { x, y }))
+            .filter(|coordinates| self.in_bounds(*coordinates))
+    }
+
+    /// Returns the neighbor tiles of the coordinates, in the order of `Neighborhood::offsets`.
+    /// Across a wrapped edge, the neighbors are on the opposite edge.
+    pub fn iter_neighbors(
+        &self,
+        coordinates: Coordinates,
+    ) -> impl Iterator<Item = Coordinates> + '_ {
+        let wraps_x = self.wraps_horizontally();
+        let wraps_y = self.wraps_vertically();
+        let (width, height) = (self.width as i32, self.height as i32);
+        let offsets = self.neighborhood.offsets(self.topology, coordinates.y);
+
+        (0..offsets.len())
+            .map(move |index| {
+                let (x, y) = offsets[index];
+                let x = coordinates.x as i32 + x as i32;
+                let y = coordinates.y as i32 + y as i32;
+                let x = if wraps_x { x.rem_euclid(width) } else { x };
+                let y = if wraps_y { y.rem_euclid(height) } else { y };
+                (x, y)
+            })
+            .filter(move |(x, y)| (0..width).contains(x) && (0..height).contains(y))
+            .map(|(x, y)| Coordinates {
+                x: x as u16,
+                y: y as u16,
+            })
+            .filter(|coordinates| self.in_bounds(*coordinates))
+    }
+
+    /// Returns the tile closest to the middle of the grid, the first one in left-to-right then
+    /// bottom-to-top order on ties, `None` when the grid has no tile
+    pub fn center_tile(&self) -> Option<Coordinates> {
+        let (center_x, center_y) = (self.width as i32 / 2, self.height as i32 / 2);
+        self.iter_coordinates().min_by_key(|coordinates| {
+            let (x, y) = (
+                coordinates.x as i32 - center_x,
+                coordinates.y as i32 - center_y,
+            );
+            x * x + y * y
+        })
+    }
+
+    /// The number of neighbors of a tile away from the edges of the grid
+    pub fn neighbor_count(&self) -> usize {
+        self.neighborhood.offsets(self.topology, 0).len()
+    }
+
+    /// Do the left and right edges touch
+    pub fn wraps_horizontally(&self) -> bool {
+        self.wrap.is_horizontal() && self.width > 2 * self.neighborhood.reach().0
+    }
+
+    /// Do the bottom and top edges touch
+    pub fn wraps_vertically(&self) -> bool {
+        self.wrap.is_vertical()
+            && self.height > 2 * self.neighborhood.reach().1
+            && (self.topology != Topology::Hex || self.height.is_multiple_of(2))
+    }
+
+    /// Returns the index of the coordinates in left-to-right then bottom-to-top order, void cells
+    /// included
+    pub fn index(&self, coordinates: Coordinates) -> usize {
+        coordinates.y as usize * self.width as usize + coordinates.x as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Game, GameStatus, TileMap};
+
+    fn c(x: u16, y: u16) -> Coordinates {
+        Coordinates { x, y }
+    }
+
+    fn neighbors(grid: &Grid, coordinates: Coordinates) -> Vec<Coordinates> {
+        let mut neighbors: Vec<Coordinates> = grid.iter_neighbors(coordinates).collect();
+        neighbors.sort();
+        neighbors
+    }
+
+    fn wrapped(wrap: Wrap, width: u16, height: u16) -> Grid {
+        Grid {
+            wrap,
+            ..Grid::new(width, height)
+        }
+    }
+
+    #[test]
+    fn horizontal_wrap_joins_the_left_and_right_edges() {
+        let grid = wrapped(Wrap::Horizontal, 5, 5);
+        assert_eq!(
+            neighbors(&grid, c(0, 0)),
+            [c(0, 1), c(1, 0), c(1, 1), c(4, 0), c(4, 1)]
+        );
+    }
+
+    #[test]
+    fn vertical_wrap_joins_the_bottom_and_top_edges() {
+        let grid = wrapped(Wrap::Vertical, 5, 5);
+        assert_eq!(
+            neighbors(&grid, c(0, 0)),
+            [c(0, 1), c(0, 4), c(1, 0), c(1, 1), c(1, 4)]
+        );
+    }
+
+    #[test]
+    fn both_wraps_give_corners_every_neighbor() {
+        let grid = wrapped(Wrap::Both, 5, 5);
+        assert_eq!(
+            neighbors(&grid, c(4, 4)),
+            [
+                c(0, 0),
+                c(0, 3),
+                c(0, 4),
+                c(3, 0),
+                c(3, 3),
+                c(3, 4),
+                c(4, 0),
+                c(4, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn short_axes_do_not_wrap() {
+        // A tile would be the neighbor of another one from both sides
+        let grid = wrapped(Wrap::Both, 2, 5);
+        assert!(!grid.wraps_horizontally());
+        assert!(grid.wraps_vertically());
+        assert_eq!(neighbors(&grid, c(0, 2)).len(), 5);
+    }
+
+    #[test]
+    fn openings_flood_across_wrapped_edges() {
+        // A wall of bombs down the middle column, the left side opens into the right one
+        let bombs = [c(2, 0), c(2, 1), c(2, 2)];
+        let mut game =
+            Game::new(TileMap::new_with_bombs_at(5, 3, &bombs).with_wrap(Wrap::Horizontal));
+        game.reveal(c(0, 1));
+        assert_eq!(game.status(), GameStatus::Won);
+
+        let mut game = Game::new(TileMap::new_with_bombs_at(5, 3, &bombs));
+        game.reveal(c(0, 1));
+        assert!(game.is_covered(c(4, 1)));
+
+        // The same wall across the rows, the bottom opens into the top
+        let bombs = [c(0, 2), c(1, 2), c(2, 2)];
+        let mut game =
+            Game::new(TileMap::new_with_bombs_at(3, 5, &bombs).with_wrap(Wrap::Vertical));
+        game.reveal(c(1, 0));
+        assert_eq!(game.status(), GameStatus::Won);
+    }
+
+    #[test]
+    fn center_tile_skips_void_cells() {
+        assert_eq!(
+            Grid::new(5, 3).center_tile(),
+            Some(Coordinates { x: 2, y: 1 })
+        );
+
+        // A ring whose middle is void
+        let grid = Grid {
+            mask: Some(Mask::from_ascii("###\n#.#\n###")),
+            ..Grid::new(3, 3)
+        };
+        assert_eq!(grid.center_tile(), Some(Coordinates { x: 1, y: 0 }));
+
+        // An L whose arms are as close to the middle, the bottom one comes first
+        let grid = Grid {
+            mask: Some(Mask::from_ascii("#....\n#....\n#....\n#....\n#####")),
+            ..Grid::new(5, 5)
+        };
+        assert_eq!(grid.center_tile(), Some(Coordinates { x: 2, y: 0 }));
+
+        let grid = Grid {
+            mask: Some(Mask::from_ascii("...")),
+            ..Grid::new(3, 1)
+        };
+        assert_eq!(grid.center_tile(), None);
+    }
+}
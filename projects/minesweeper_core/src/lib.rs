@@ -0,0 +1,50 @@
+//! The rules of minesweeper without any rendering or input: tile maps and their generation, the
+//! state of a game in progress, the actions a player can take, replays of those actions and a
+//! solver.
+//!
+//! The `bevy` feature derives the Bevy traits needed to use the types in an ECS, the `serde`
+//! feature derives the serde traits needed to save a game or a replay.
+
+pub mod solver;
+
+mod coordinates;
+mod game;
+mod game_snapshot;
+mod game_status;
+mod grid;
+mod mask;
+mod neighborhood;
+mod outcome;
+mod pending_layout;
+mod replay;
+mod replay_error;
+mod replay_input;
+mod replay_step;
+mod snapshot_error;
+mod tile;
+mod tile_map;
+mod tile_map_analysis;
+mod tile_state;
+mod topology;
+mod wrap;
+
+pub use coordinates::*;
+pub use game::*;
+pub use game_snapshot::*;
+pub use game_status::*;
+pub use grid::*;
+pub use mask::*;
+pub use neighborhood::*;
+pub use outcome::*;
+pub use pending_layout::*;
+pub use replay::*;
+pub use replay_error::*;
+pub use replay_input::*;
+pub use replay_step::*;
+pub use snapshot_error::*;
+pub use tile::*;
+pub use tile_map::*;
+pub use tile_map_analysis::*;
+pub use tile_state::*;
+pub use topology::*;
+pub use wrap::*;
@@ -0,0 +1,146 @@
+use crate::Coordinates;
+
+/// The shape of a map: which cells of its rectangle are tiles, the others are void. Void cells
+/// hold no bomb, are never revealed and are nobody's neighbor.
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mask {
+    width: u16,
+    height: u16,
+    // Stores cells in left-to-right then bottom-to-top layout, true for a tile
+    cells: Vec<bool>,
+}
+
+impl Mask {
+    /// Creates a mask whose tiles are the coordinates for which `is_tile` returns true
+    pub fn from_fn(width: u16, height: u16, is_tile: impl Fn(Coordinates) -> bool) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coordinates { x, y }))
+            .map(is_tile)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a mask from ASCII art, one line per row from the top row to the bottom one. Spaces
+    /// and dots are void cells, any other character is a tile. The mask is as wide as the longest
+    /// line, the end of a shorter line is void. Empty lines at the start and at the end are
+    /// ignored.
+    pub fn from_ascii(art: &str) -> Self {
+        let rows: Vec<Vec<bool>> = art
+            .trim_matches(|c| c == '\n' || c == '\r')
+            .lines()
+            .map(|line| line.chars().map(|c| c != ' ' && c != '.').collect())
+            .collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0) as u16;
+        let height = rows.len() as u16;
+
+        Self::from_fn(width, height, |coordinates| {
+            let row = &rows[(height - 1 - coordinates.y) as usize];
+            row.get(coordinates.x as usize).copied().unwrap_or(false)
+        })
+    }
+
+    /// The number of cells in a row
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// The number of rows
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns true if the cell at the coordinates is a tile; otherwise false, outside of the
+    /// mask too
+    pub fn contains(&self, coordinates: Coordinates) -> bool {
+        coordinates.x < self.width
+            && coordinates.y < self.height
+            && self.cells[coordinates.y as usize * self.width as usize + coordinates.x as usize]
+    }
+
+    /// Returns true if the mask has a cell for every coordinates of its rectangle; otherwise false,
+    /// which only happens to a mask deserialized from malformed data
+    pub fn is_valid(&self) -> bool {
+        self.cells.len() == self.width as usize * self.height as usize
+    }
+
+    /// Returns true if the mask is valid and has the size of a map; otherwise false
+    pub fn fits(&self, width: u16, height: u16) -> bool {
+        self.is_valid() && self.width == width && self.height == height
+    }
+
+    /// The number of cells which are tiles
+    pub fn tile_count(&self) -> usize {
+        self.cells.iter().filter(|cell| **cell).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_ascii_reads_rows_from_the_top() {
+        let mask = Mask::from_ascii("\n#..\n ##\n");
+
+        assert_eq!((mask.width(), mask.height()), (3, 2));
+        assert!(mask.contains(Coordinates { x: 0, y: 1 }));
+        assert!(!mask.contains(Coordinates { x: 1, y: 1 }));
+        assert!(!mask.contains(Coordinates { x: 0, y: 0 }));
+        assert!(mask.contains(Coordinates { x: 2, y: 0 }));
+        assert!(!mask.contains(Coordinates { x: 3, y: 0 }));
+        assert_eq!(mask.tile_count(), 3);
+        assert!(mask.fits(3, 2));
+    }
+
+    #[test]
+    fn from_ascii_pads_ragged_rows_with_void() {
+        let mask = Mask::from_ascii("#\n###\n##");
+
+        assert_eq!((mask.width(), mask.height()), (3, 3));
+        assert!(mask.contains(Coordinates { x: 0, y: 2 }));
+        assert!(!mask.contains(Coordinates { x: 1, y: 2 }));
+        assert!(!mask.contains(Coordinates { x: 2, y: 2 }));
+        assert!(mask.contains(Coordinates { x: 2, y: 1 }));
+        assert!(!mask.contains(Coordinates { x: 2, y: 0 }));
+        assert_eq!(mask.tile_count(), 6);
+        assert!(mask.is_valid());
+    }
+
+    #[test]
+    fn from_ascii_of_nothing_is_empty() {
+        for art in ["", "\n\n", ".. \n . "] {
+            let mask = Mask::from_ascii(art);
+            assert_eq!(mask.tile_count(), 0);
+            assert!(mask.is_valid());
+        }
+        assert_eq!(Mask::from_ascii("\n").width(), 0);
+        assert_eq!(Mask::from_ascii("\n").height(), 0);
+    }
+
+    #[test]
+    fn masks_only_fit_maps_of_their_size() {
+        let mask = Mask::from_ascii("###\n###");
+        assert!(mask.fits(3, 2));
+        assert!(!mask.fits(2, 3));
+        assert!(!mask.fits(3, 3));
+    }
+
+    #[test]
+    fn mask_missing_cells_is_invalid() {
+        let mask = Mask {
+            width: 3,
+            height: 3,
+            cells: vec![true; 4],
+        };
+
+        assert!(!mask.is_valid());
+        assert!(!mask.fits(3, 3));
+    }
+}
@@ -0,0 +1,207 @@
+use crate::Topology;
+use std::borrow::Cow;
+
+/// Which tiles around a tile are its neighbors: the ones whose bombs its number counts, which an
+/// opening reveals and which a chord reveals. Numbers can not count more than 255 bombs.
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Neighborhood {
+    /// The tiles touching the tile
+    #[default]
+    Adjacent,
+    /// The tiles sharing a side with the tile, on square tiles
+    Cross,
+    /// The tiles a knight moves to from the tile, on square tiles
+    Knight,
+    /// The tiles at most this many steps away from the tile
+    Radius(u8),
+    /// The tiles at these offsets from the tile, which must not repeat nor include `(0, 0)`.
+    /// On hex tiles, the offsets are the same on every row.
+    Custom(Vec<(i8, i8)>),
+}
+
+impl Neighborhood {
+    const CROSS_OFFSETS: [(i8, i8); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+    const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+        (-1, -2),
+        (1, -2),
+        (-2, -1),
+        (2, -1),
+        (-2, 1),
+        (2, 1),
+        (-1, 2),
+        (1, 2),
+    ];
+
+    /// Returns the offset coordinates which can be added to the coordinates of a tile on the row
+    /// `y` to find its neighbors, bottom to top then left to right
+    pub fn offsets(&self, topology: Topology, y: u16) -> Cow<'_, [(i8, i8)]> {
+        match self {
+            Self::Adjacent => Cow::Borrowed(topology.neighbor_offsets(y)),
+            Self::Cross => Cow::Borrowed(&Self::CROSS_OFFSETS),
+            Self::Knight => Cow::Borrowed(&Self::KNIGHT_OFFSETS),
+            Self::Radius(radius) => Cow::Owned(Self::radius_offsets(*radius, topology, y)),
+            Self::Custom(offsets) => Cow::Borrowed(offsets),
+        }
+    }
+
+    /// Returns how far the neighbors of a tile can be on each axis
+    pub fn reach(&self) -> (u16, u16) {
+        match self {
+            Self::Adjacent | Self::Cross => (1, 1),
+            Self::Knight => (2, 2),
+            Self::Radius(radius) => (*radius as u16, *radius as u16),
+            Self::Custom(offsets) => offsets.iter().fold((0, 0), |(x, y), offset| {
+                (
+                    x.max(offset.0.unsigned_abs() as u16),
+                    y.max(offset.1.unsigned_abs() as u16),
+                )
+            }),
+        }
+    }
+
+    /// Returns the offsets of the tiles at most `radius` steps away from a tile on the row `y`.
+    /// Hex tiles are measured in cube coordinates, where the shift of the odd rows cancels out.
+    fn radius_offsets(radius: u8, topology: Topology, y: u16) -> Vec<(i8, i8)> {
+        let radius = radius as i32;
+        let row = y as i32;
+        let in_radius = |x: i32, y: i32| match topology {
+            Topology::Square => x.abs() <= radius,
+            Topology::Hex => {
+                let q = x - ((row + y).div_euclid(2) - row.div_euclid(2));
+                (q.abs() + y.abs() + (q + y).abs()) / 2 <= radius
+            }
+        };
+
+        // Hex rows reach further on the side they are shifted to
+        (-radius..=radius)
+            .flat_map(|y| (-radius - 1..=radius + 1).map(move |x| (x, y)))
+            .filter(|&(x, y)| (x, y) != (0, 0) && in_radius(x, y))
+            .map(|(x, y)| (x as i8, y as i8))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coordinates, Game, GameStatus, Grid, Outcome, Tile, TileMap};
+
+    fn c(x: u16, y: u16) -> Coordinates {
+        Coordinates { x, y }
+    }
+
+    fn neighbors(grid: &Grid, coordinates: Coordinates) -> Vec<Coordinates> {
+        let mut neighbors: Vec<Coordinates> = grid.iter_neighbors(coordinates).collect();
+        neighbors.sort();
+        neighbors
+    }
+
+    fn sorted(offsets: &[(i8, i8)]) -> Vec<(i8, i8)> {
+        let mut offsets = offsets.to_vec();
+        offsets.sort();
+        offsets
+    }
+
+    #[test]
+    fn cross_counts_the_tiles_sharing_a_side() {
+        let grid = Grid {
+            neighborhood: Neighborhood::Cross,
+            ..Grid::new(5, 5)
+        };
+        assert_eq!(
+            neighbors(&grid, c(2, 2)),
+            [c(1, 2), c(2, 1), c(2, 3), c(3, 2)]
+        );
+        assert_eq!(neighbors(&grid, c(0, 0)), [c(0, 1), c(1, 0)]);
+    }
+
+    #[test]
+    fn knight_counts_the_tiles_a_knight_moves_to() {
+        let grid = Grid {
+            neighborhood: Neighborhood::Knight,
+            ..Grid::new(5, 5)
+        };
+        assert_eq!(neighbors(&grid, c(2, 2)).len(), 8);
+        assert_eq!(neighbors(&grid, c(0, 0)), [c(1, 2), c(2, 1)]);
+        assert_eq!(Neighborhood::Knight.reach(), (2, 2));
+    }
+
+    #[test]
+    fn radius_counts_the_tiles_within_a_number_of_steps() {
+        let offsets = Neighborhood::Radius(2).offsets(Topology::Square, 0);
+        assert_eq!(offsets.len(), 24);
+        assert!(offsets.contains(&(-2, 2)) && !offsets.contains(&(0, 0)));
+
+        // On hex tiles, a radius of one is the adjacent tiles of both kinds of rows
+        for y in [0, 1] {
+            assert_eq!(
+                sorted(&Neighborhood::Radius(1).offsets(Topology::Hex, y)),
+                sorted(Topology::Hex.neighbor_offsets(y))
+            );
+            assert_eq!(Neighborhood::Radius(2).offsets(Topology::Hex, y).len(), 18);
+        }
+    }
+
+    #[test]
+    fn custom_offsets_are_used_as_given() {
+        let neighborhood = Neighborhood::Custom(vec![(0, 2), (-3, 0)]);
+        assert_eq!(
+            neighborhood.offsets(Topology::Hex, 1).as_ref(),
+            [(0, 2), (-3, 0)]
+        );
+        assert_eq!(neighborhood.reach(), (3, 2));
+
+        let grid = Grid {
+            neighborhood,
+            ..Grid::new(5, 5)
+        };
+        assert_eq!(neighbors(&grid, c(3, 1)), [c(0, 1), c(3, 3)]);
+    }
+
+    #[test]
+    fn numbers_count_past_eight() {
+        let bombs: Vec<Coordinates> = Grid::new(5, 5)
+            .iter_coordinates()
+            .filter(|coordinates| *coordinates != c(2, 2))
+            .collect();
+        let tile_map =
+            TileMap::new_with_bombs_at(5, 5, &bombs).with_neighborhood(Neighborhood::Radius(2));
+
+        assert_eq!(tile_map.map()[2][2], Tile::BombNeighbor(24));
+    }
+
+    #[test]
+    fn openings_follow_the_neighborhood() {
+        // Across a side from the bomb, the middle tile is empty and opens the whole map
+        let tile_map = TileMap::new_with_bombs_at(3, 3, &[c(0, 0)]);
+        let mut game = Game::new(tile_map.clone().with_neighborhood(Neighborhood::Cross));
+        game.reveal(c(1, 1));
+        assert_eq!(game.status(), GameStatus::Won);
+
+        // Touching the bomb, it is a number
+        let mut game = Game::new(tile_map);
+        assert_eq!(game.reveal(c(1, 1)).len(), 1);
+    }
+
+    #[test]
+    fn chords_follow_the_neighborhood() {
+        let tile_map = TileMap::new_with_bombs_at(3, 3, &[c(0, 0)]);
+        let mut game = Game::new(tile_map.with_neighborhood(Neighborhood::Knight));
+        game.reveal(c(2, 1));
+        game.toggle_flag(c(0, 0));
+        let outcomes = game.chord(c(2, 1));
+
+        // The other knight move is revealed, not the tiles touching the number
+        assert_eq!(
+            outcomes[0],
+            Outcome::Revealed {
+                coordinates: c(0, 2),
+                distance: 0
+            }
+        );
+        assert!(game.is_covered(c(1, 1)));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::Coordinates;
+
+/// A state change caused by an action, in the order it happened
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq)]
+pub enum Outcome {
+    /// The bombs of a pending layout were placed around the first revealed tile. `solvable` is set
+    /// when the layout had to be solvable without guessing and such a layout was found within the
+    /// `attempts`.
+    LaidOut { attempts: u32, solvable: bool },
+
+    /// A flag was placed on the tile
+    Flagged(Coordinates),
+
+    /// A flag of the tile was removed, either by the player or by an opening revealing it
+    Unflagged(Coordinates),
+
+    /// The tile was revealed `distance` steps away from the tiles targeted by the action, following
+    /// the opening it belongs to
+    Revealed {
+        coordinates: Coordinates,
+        distance: u32,
+    },
+
+    /// The revealed tile was a bomb, the game is lost
+    Exploded(Coordinates),
+
+    /// Every safe tile is revealed, the remaining bombs were flagged and the game is won
+    Won,
+
+    /// The revealed tile was covered again by an undo
+    Covered(Coordinates),
+
+    /// The explosion of the tile was taken back by an undo, the game is played again
+    Defused(Coordinates),
+}
@@ -0,0 +1,12 @@
+/// Describes a tile map whose bombs are placed when the first tile is triggered
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingLayout {
+    /// The number of bombs to place
+    pub bomb_count: u16,
+    /// Are the neighbors of the triggered tile kept free of bombs
+    pub opening: bool,
+    /// The attempt budget when the layout must be solvable without guessing
+    pub no_guess_max_attempts: Option<u32>,
+}
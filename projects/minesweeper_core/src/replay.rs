@@ -0,0 +1,154 @@
+use crate::{Game, GameSnapshot, ReplayError, ReplayInput, ReplayStep, SnapshotError};
+
+/// The inputs made on a game with their time, from the game they started on to the game they ended
+/// on. Playing the inputs again is deterministic: the seed of a pending layout is part of the
+/// starting game.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Replay {
+    /// The game before the first input
+    pub start: GameSnapshot,
+    /// The inputs, by increasing time
+    pub steps: Vec<ReplayStep>,
+    /// The game after the last input
+    pub end: GameSnapshot,
+}
+
+impl Replay {
+    /// Starts recording the inputs made on a game
+    pub fn new(game: &Game) -> Self {
+        let start = game.snapshot();
+        Self {
+            end: start.clone(),
+            start,
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, time: f32, input: ReplayInput) {
+        self.steps.push(ReplayStep { time, input });
+    }
+
+    /// Stops recording, keeping the game the inputs ended on
+    pub fn finish(&mut self, game: &Game) {
+        self.end = game.snapshot();
+    }
+
+    /// The time of the last input
+    pub fn duration(&self) -> f32 {
+        self.steps.last().map_or(0., |step| step.time)
+    }
+
+    /// The number of steps made up to `time`
+    pub fn steps_until(&self, time: f32) -> usize {
+        self.steps.partition_point(|step| step.time <= time)
+    }
+
+    /// Plays the inputs made up to `time` on the starting game
+    pub fn game_at(&self, time: f32) -> Result<Game, SnapshotError> {
+        let mut game = Game::from_snapshot(&self.start)?;
+        for step in &self.steps[..self.steps_until(time)] {
+            step.input.apply(&mut game);
+        }
+
+        Ok(game)
+    }
+
+    /// Plays every input and checks that the game ends as recorded, returning the final game
+    pub fn verify(&self) -> Result<Game, ReplayError> {
+        if let Some(index) =
+            (1..self.steps.len()).find(|index| self.steps[*index].time < self.steps[index - 1].time)
+        {
+            return Err(ReplayError::UnorderedStep(index));
+        }
+
+        let game = self.game_at(f32::INFINITY)?;
+        if game.snapshot() != self.end {
+            return Err(ReplayError::Mismatch);
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coordinates, GameStatus, PendingLayout};
+
+    /// Records a few inputs on a 9x9 game whose bombs are placed on the first reveal
+    fn recorded_replay() -> Replay {
+        let pending_layout = PendingLayout {
+            bomb_count: 10,
+            opening: true,
+            no_guess_max_attempts: None,
+        };
+        let mut game = Game::with_pending_layout(9, 9, pending_layout, 42);
+        let mut replay = Replay::new(&game);
+
+        let mut play = |game: &mut Game, input: ReplayInput| {
+            input.apply(game);
+            replay.record(replay.steps.len() as f32, input);
+        };
+        play(&mut game, ReplayInput::Trigger(Coordinates { x: 4, y: 4 }));
+        // The tile to mark is only known once the first reveal opened the map
+        let covered = game
+            .tile_map()
+            .iter_coordinates()
+            .find(|coordinates| game.is_covered(*coordinates))
+            .unwrap();
+        play(&mut game, ReplayInput::Mark(covered));
+        play(&mut game, ReplayInput::Undo);
+        play(&mut game, ReplayInput::Redo);
+        replay.finish(&game);
+
+        replay
+    }
+
+    #[test]
+    fn recorded_replay_verifies() {
+        let replay = recorded_replay();
+        let game = replay.verify().unwrap();
+
+        assert_eq!(game.snapshot(), replay.end);
+        assert_eq!(game.status(), GameStatus::Playing);
+        assert_eq!(game.flagged_count(), 1);
+    }
+
+    #[test]
+    fn game_at_plays_the_inputs_made_until_then() {
+        let replay = recorded_replay();
+
+        assert_eq!(replay.game_at(-1.).unwrap().snapshot(), replay.start);
+        assert_eq!(replay.game_at(1.).unwrap().flagged_count(), 1);
+        assert_eq!(replay.game_at(2.).unwrap().flagged_count(), 0);
+        assert_eq!(replay.game_at(3.).unwrap().snapshot(), replay.end);
+    }
+
+    #[test]
+    fn tampered_replay_fails() {
+        let replay = recorded_replay();
+
+        let mut tampered = replay.clone();
+        tampered.steps.remove(3);
+        assert_eq!(tampered.verify().unwrap_err(), ReplayError::Mismatch);
+
+        let mut tampered = replay.clone();
+        tampered.steps[0].input = ReplayInput::Trigger(Coordinates { x: 0, y: 0 });
+        assert_eq!(tampered.verify().unwrap_err(), ReplayError::Mismatch);
+
+        let mut tampered = replay.clone();
+        tampered.steps[2].time = 0.5;
+        assert_eq!(
+            tampered.verify().unwrap_err(),
+            ReplayError::UnorderedStep(2)
+        );
+
+        let mut tampered = replay;
+        tampered.start.width = 0;
+        assert_eq!(
+            tampered.verify().unwrap_err(),
+            ReplayError::InvalidStart(SnapshotError::EmptyMap)
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::SnapshotError;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Why a `Replay` does not reproduce the game it was recorded from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReplayError {
+    /// The game the replay starts from can not be restored
+    InvalidStart(SnapshotError),
+    /// The step at the index was made before the previous one
+    UnorderedStep(usize),
+    /// Playing the steps does not end on the recorded game
+    Mismatch,
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidStart(error) => write!(f, "invalid starting game: {}", error),
+            Self::UnorderedStep(index) => {
+                write!(f, "step {} was made before the previous one", index)
+            }
+            Self::Mismatch => write!(f, "the steps do not end on the recorded game"),
+        }
+    }
+}
+
+impl Error for ReplayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidStart(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<SnapshotError> for ReplayError {
+    fn from(error: SnapshotError) -> Self {
+        Self::InvalidStart(error)
+    }
+}
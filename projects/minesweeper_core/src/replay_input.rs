@@ -0,0 +1,30 @@
+use crate::{Coordinates, Game, Outcome};
+
+/// An input of the player on a board, as sent through the tile events
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplayInput {
+    /// Reveals a covered tile or chords a revealed one
+    Trigger(Coordinates),
+    /// Flags a covered tile or removes its flag
+    Mark(Coordinates),
+    /// Chords a revealed tile
+    Chord(Coordinates),
+    /// Takes back the last action
+    Undo,
+    /// Applies the last undone action again
+    Redo,
+}
+
+impl ReplayInput {
+    /// Applies the input to a game the way the tile events are
+    pub fn apply(&self, game: &mut Game) -> Vec<Outcome> {
+        match *self {
+            Self::Trigger(coordinates) => game.trigger(coordinates),
+            Self::Mark(coordinates) => game.toggle_flag(coordinates),
+            Self::Chord(coordinates) => game.chord(coordinates),
+            Self::Undo => game.undo(),
+            Self::Redo => game.redo(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use crate::ReplayInput;
+
+/// An input of a replay and when it was made
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayStep {
+    /// Seconds elapsed since the recording started
+    pub time: f32,
+    /// The input made
+    pub input: ReplayInput,
+}
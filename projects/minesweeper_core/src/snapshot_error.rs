@@ -0,0 +1,56 @@
+use crate::Coordinates;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Why a `GameSnapshot` does not describe a game which can be restored
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnapshotError {
+    /// The map has no tile
+    EmptyMap,
+    /// The mask does not have a cell for every tile of the map
+    InvalidMask,
+    /// The bombs do not leave a single safe tile
+    TooManyBombs {
+        bomb_count: usize,
+        tile_count: usize,
+    },
+    /// The coordinates are outside of the map or on a void cell
+    OutOfBounds(Coordinates),
+    /// The coordinates are listed in a set more often than a tile can hold
+    Duplicate(Coordinates),
+    /// The tile is both revealed and flagged
+    FlaggedRevealed(Coordinates),
+    /// Tiles are revealed or bombs placed while the layout is still pending
+    PendingLayoutStarted,
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyMap => write!(f, "the map has no tile"),
+            Self::InvalidMask => write!(f, "the mask does not match the map"),
+            Self::TooManyBombs {
+                bomb_count,
+                tile_count,
+            } => write!(
+                f,
+                "{} bombs do not leave a safe tile among {} tiles",
+                bomb_count, tile_count
+            ),
+            Self::OutOfBounds(coordinates) => {
+                write!(f, "tile {} is outside of the map", coordinates)
+            }
+            Self::Duplicate(coordinates) => write!(f, "tile {} is listed twice", coordinates),
+            Self::FlaggedRevealed(coordinates) => {
+                write!(f, "tile {} is both revealed and flagged", coordinates)
+            }
+            Self::PendingLayoutStarted => {
+                write!(f, "tiles are revealed or bombs placed before the layout")
+            }
+        }
+    }
+}
+
+impl Error for SnapshotError {}
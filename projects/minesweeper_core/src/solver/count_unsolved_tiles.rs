@@ -0,0 +1,8 @@
+use crate::{solver::*, Coordinates, TileMap};
+
+/// Plays `tile_map` from `start` using only logical deductions and returns the number of safe tiles
+/// left covered once no more deductions can be made. Zero means the map is solvable without
+/// guessing.
+pub fn count_unsolved_tiles(tile_map: &TileMap, start: Coordinates) -> usize {
+    unsolved_tiles(tile_map, start).len()
+}
@@ -0,0 +1,256 @@
+use crate::{solver::*, Coordinates};
+use std::collections::{BTreeSet, HashMap};
+
+/// A revealed number restricted to its undecided covered neighbors
+struct Constraint {
+    cells: BTreeSet<Coordinates>,
+    mines: usize,
+}
+
+/// Finds the covered tiles of `board` which are provably safe or provably mines.
+///
+/// Flagged tiles are trusted to be mines. The following rules are applied, cheapest first, until
+/// none of them makes progress:
+/// 1. Single point: a number whose undecided neighbors are all safe or all mines
+/// 2. Subset and superset: two overlapping numbers whose difference is forced
+/// 3. Global count: the remaining mines are zero or fill every undecided tile
+pub fn deduce(board: &VisibleBoard) -> Deductions {
+    let mut deductions = Deductions::default();
+
+    loop {
+        let constraints = constraints(board, &deductions);
+        let progress = single_point(&constraints, &mut deductions)
+            || subset_superset(&constraints, &mut deductions)
+            || global_count(board, &mut deductions);
+
+        if !progress {
+            return deductions;
+        }
+    }
+}
+
+fn constraints(board: &VisibleBoard, deductions: &Deductions) -> Vec<Constraint> {
+    board
+        .iter_coordinates()
+        .filter_map(|coordinates| {
+            let number = match board.get(coordinates) {
+                VisibleTile::Revealed(number) => number,
+                _ => return None,
+            };
+
+            let mut cells = BTreeSet::new();
+            let mut known_mines = 0;
+            for neighbor in board.iter_neighbors(coordinates) {
+                match board.get(neighbor) {
+                    VisibleTile::Covered if deductions.mines.contains(&neighbor) => {
+                        known_mines += 1
+                    }
+                    VisibleTile::Covered if !deductions.safe.contains(&neighbor) => {
+                        cells.insert(neighbor);
+                    }
+                    VisibleTile::Flagged => known_mines += 1,
+                    _ => (),
+                }
+            }
+
+            if cells.is_empty() {
+                return None;
+            }
+
+            // Wrong flags can make a number inconsistent, such numbers prove nothing
+            let mines = (number as usize).checked_sub(known_mines)?;
+            if mines > cells.len() {
+                return None;
+            }
+
+            Some(Constraint { cells, mines })
+        })
+        .collect()
+}
+
+fn single_point(constraints: &[Constraint], deductions: &mut Deductions) -> bool {
+    let mut progress = false;
+    for constraint in constraints {
+        if constraint.mines == 0 {
+            progress |= mark(&mut deductions.safe, constraint.cells.iter().copied());
+        } else if constraint.mines == constraint.cells.len() {
+            progress |= mark(&mut deductions.mines, constraint.cells.iter().copied());
+        }
+    }
+    progress
+}
+
+fn subset_superset(constraints: &[Constraint], deductions: &mut Deductions) -> bool {
+    // Index the constraints by cell so only overlapping constraints are compared
+    let mut constraints_by_cell: HashMap<Coordinates, Vec<usize>> = HashMap::new();
+    for (index, constraint) in constraints.iter().enumerate() {
+        for cell in constraint.cells.iter() {
+            constraints_by_cell.entry(*cell).or_default().push(index);
+        }
+    }
+
+    let mut progress = false;
+    for (index, a) in constraints.iter().enumerate() {
+        let overlapping: BTreeSet<usize> = a
+            .cells
+            .iter()
+            .flat_map(|cell| constraints_by_cell[cell].iter().copied())
+            .filter(|other_index| *other_index != index)
+            .collect();
+
+        for other_index in overlapping {
+            let b = &constraints[other_index];
+            let only_a: Vec<Coordinates> = a.cells.difference(&b.cells).copied().collect();
+
+            // At most `b.mines` of the mines of `a` are shared with `b`. When the rest exactly
+            // fills the cells only in `a`, those cells are mines and the cells only in `b` are
+            // safe. When `a` is a subset of `b` this reduces to "equal counts make the difference
+            // safe", and swapping `a` and `b` covers "the difference holds every extra mine".
+            if a.mines >= b.mines && a.mines - b.mines == only_a.len() {
+                progress |= mark(&mut deductions.mines, only_a.iter().copied());
+                progress |= mark(&mut deductions.safe, b.cells.difference(&a.cells).copied());
+            }
+        }
+    }
+    progress
+}
+
+fn global_count(board: &VisibleBoard, deductions: &mut Deductions) -> bool {
+    let mut flagged = 0;
+    let mut undecided = Vec::new();
+    for coordinates in board.iter_coordinates() {
+        match board.get(coordinates) {
+            VisibleTile::Flagged => flagged += 1,
+            VisibleTile::Covered
+                if !deductions.safe.contains(&coordinates)
+                    && !deductions.mines.contains(&coordinates) =>
+            {
+                undecided.push(coordinates)
+            }
+            _ => (),
+        }
+    }
+
+    if undecided.is_empty() {
+        return false;
+    }
+
+    let remaining =
+        match (board.bomb_count() as usize).checked_sub(flagged + deductions.mines.len()) {
+            None => return false,
+            Some(remaining) => remaining,
+        };
+
+    if remaining == 0 {
+        mark(&mut deductions.safe, undecided)
+    } else if remaining == undecided.len() {
+        mark(&mut deductions.mines, undecided)
+    } else {
+        false
+    }
+}
+
+/// Inserts the cells into the set, returning true if any of them is new
+fn mark(set: &mut BTreeSet<Coordinates>, cells: impl IntoIterator<Item = Coordinates>) -> bool {
+    cells
+        .into_iter()
+        .fold(false, |progress, cell| set.insert(cell) | progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    /// A board whose tiles are covered except the revealed numbers
+    fn numbered_board(
+        width: u16,
+        height: u16,
+        bomb_count: u16,
+        numbers: &[(u16, u16, u8)],
+    ) -> VisibleBoard {
+        let mut board = VisibleBoard::covered(Grid::new(width, height), bomb_count);
+        for (x, y, number) in numbers {
+            board.set(Coordinates { x: *x, y: *y }, VisibleTile::Revealed(*number));
+        }
+        board
+    }
+
+    fn cells(cells: &[(u16, u16)]) -> BTreeSet<Coordinates> {
+        cells
+            .iter()
+            .map(|(x, y)| Coordinates { x: *x, y: *y })
+            .collect()
+    }
+
+    #[test]
+    fn single_point_finds_mines_and_safe_tiles() {
+        // Three 1s around the only covered tile
+        let board = numbered_board(2, 2, 1, &[(0, 0, 1), (1, 0, 1), (1, 1, 1)]);
+        let deductions = deduce(&board);
+
+        assert_eq!(deductions.mines, cells(&[(0, 1)]));
+        assert!(deductions.safe.is_empty());
+
+        // The 0 clears the two tiles under it, leaving the last 1 a single covered neighbor
+        let board = numbered_board(3, 2, 1, &[(0, 0, 0), (1, 0, 1), (2, 0, 1)]);
+        let deductions = deduce(&board);
+
+        assert_eq!(deductions.safe, cells(&[(0, 1), (1, 1)]));
+        assert_eq!(deductions.mines, cells(&[(2, 1)]));
+    }
+
+    #[test]
+    fn subset_rule_solves_1_1_1() {
+        // Along a wall, each end 1 shares its two cells with the middle 1
+        let board = numbered_board(3, 2, 1, &[(0, 0, 1), (1, 0, 1), (2, 0, 1)]);
+        let deductions = deduce(&board);
+
+        assert_eq!(deductions.safe, cells(&[(0, 1), (2, 1)]));
+        assert_eq!(deductions.mines, cells(&[(1, 1)]));
+    }
+
+    #[test]
+    fn subset_rule_solves_1_2_1() {
+        // The ends of the row are covered so the walls do not help
+        let board = numbered_board(5, 2, 2, &[(1, 0, 1), (2, 0, 2), (3, 0, 1)]);
+        let deductions = deduce(&board);
+
+        assert_eq!(deductions.mines, cells(&[(1, 1), (3, 1)]));
+        assert_eq!(
+            deductions.safe,
+            cells(&[(0, 0), (0, 1), (2, 1), (4, 0), (4, 1)])
+        );
+    }
+
+    #[test]
+    fn global_count_uses_the_remaining_mines() {
+        let board = numbered_board(2, 2, 0, &[]);
+        assert_eq!(
+            deduce(&board).safe,
+            cells(&[(0, 0), (0, 1), (1, 0), (1, 1)])
+        );
+
+        let board = numbered_board(2, 2, 3, &[(0, 0, 3)]);
+        assert_eq!(deduce(&board).mines, cells(&[(0, 1), (1, 0), (1, 1)]));
+    }
+
+    #[test]
+    fn guesses_are_not_deduced() {
+        // A 1 in a corner with three covered neighbors could be any of them
+        let board = numbered_board(2, 2, 1, &[(0, 0, 1)]);
+        assert!(deduce(&board).is_empty());
+    }
+
+    #[test]
+    fn wrong_flags_prove_nothing() {
+        // Two flags around a 1 make it inconsistent
+        let mut board = numbered_board(3, 2, 4, &[(1, 0, 1)]);
+        board.set(Coordinates { x: 0, y: 1 }, VisibleTile::Flagged);
+        board.set(Coordinates { x: 2, y: 1 }, VisibleTile::Flagged);
+        let deductions = deduce(&board);
+
+        assert!(!deductions.safe.contains(&Coordinates { x: 1, y: 1 }));
+        assert!(deductions.mines.is_empty());
+    }
+}
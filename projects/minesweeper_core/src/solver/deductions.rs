@@ -0,0 +1,18 @@
+use crate::Coordinates;
+use std::collections::BTreeSet;
+
+/// The tiles a solver proved to be safe or to be mines
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Deductions {
+    /// Covered tiles which can not hold a bomb
+    pub safe: BTreeSet<Coordinates>,
+
+    /// Covered tiles which must hold a bomb
+    pub mines: BTreeSet<Coordinates>,
+}
+
+impl Deductions {
+    pub fn is_empty(&self) -> bool {
+        self.safe.is_empty() && self.mines.is_empty()
+    }
+}
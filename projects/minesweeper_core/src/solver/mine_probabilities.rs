@@ -0,0 +1,625 @@
+use crate::{solver::*, Coordinates};
+use std::collections::HashMap;
+
+/// The most states a layer of the count of a group may hold, which bounds the time and memory
+/// spent on a group whose numbers are too entangled
+const MAX_LAYER_STATES: usize = 1 << 12;
+
+/// A revealed number over covered, unflagged cells, which are referred to by index
+struct Constraint {
+    cells: Vec<usize>,
+    mines: usize,
+}
+
+/// The solutions of a group of frontier cells which share constraints, by number of mines
+struct Group {
+    cells: Vec<Coordinates>,
+    /// `solutions[k]` is the number of assignments with `k` mines
+    solutions: Vec<f64>,
+    /// `cell_solutions[k][i]` is the number of assignments with `k` mines where cell `i` is a mine
+    cell_solutions: Vec<Vec<f64>>,
+}
+
+/// Computes, for every covered and unflagged tile, the exact probability that it holds a mine given
+/// the revealed numbers, the flags and the bomb count of `board`.
+///
+/// Covered tiles next to a revealed number form the frontier, which is split into groups of tiles
+/// sharing numbers. The mine assignments of each group are counted tile by tile, merging the
+/// partial assignments which leave the same mines to place around the numbers, and the groups are
+/// combined by weighting each total of frontier mines with the number of ways to place the
+/// remaining mines on the interior tiles.
+///
+/// Flagged tiles are trusted to be mines. Fails when the flags contradict the numbers, or when a
+/// group is too entangled to be counted within `MAX_LAYER_STATES`.
+pub fn mine_probabilities(
+    board: &VisibleBoard,
+) -> Result<HashMap<Coordinates, f64>, ProbabilityError> {
+    let mut flagged = 0;
+    let mut cell_indices = HashMap::new();
+    let mut cells = Vec::new();
+    let mut constraints = Vec::new();
+
+    for coordinates in board.iter_coordinates() {
+        let number = match board.get(coordinates) {
+            VisibleTile::Flagged => {
+                flagged += 1;
+                continue;
+            }
+            VisibleTile::Covered => continue,
+            VisibleTile::Revealed(number) => number as usize,
+        };
+
+        let mut constraint = Constraint {
+            cells: Vec::new(),
+            mines: number,
+        };
+        for neighbor in board.iter_neighbors(coordinates) {
+            match board.get(neighbor) {
+                VisibleTile::Flagged => {
+                    constraint.mines = constraint
+                        .mines
+                        .checked_sub(1)
+                        .ok_or(ProbabilityError::Contradiction)?
+                }
+                VisibleTile::Covered => {
+                    let index = *cell_indices.entry(neighbor).or_insert_with(|| {
+                        cells.push(neighbor);
+                        cells.len() - 1
+                    });
+                    constraint.cells.push(index);
+                }
+                VisibleTile::Revealed(_) => (),
+            }
+        }
+
+        if constraint.mines > constraint.cells.len() {
+            return Err(ProbabilityError::Contradiction);
+        }
+        if !constraint.cells.is_empty() {
+            constraints.push(constraint);
+        }
+    }
+
+    let interior: Vec<Coordinates> = board
+        .iter_coordinates()
+        .filter(|coordinates| board.get(*coordinates).is_covered())
+        .filter(|coordinates| !cell_indices.contains_key(coordinates))
+        .collect();
+    let remaining_mines = (board.bomb_count() as usize)
+        .checked_sub(flagged)
+        .ok_or(ProbabilityError::Contradiction)?;
+
+    let mut groups_counted = Vec::new();
+    let mut too_complex = Vec::new();
+    for (group_cells, group_constraints) in groups(&cells, &constraints) {
+        match count(&cells, &constraints, group_cells, group_constraints) {
+            Ok(group) => groups_counted.push(group),
+            Err(ProbabilityError::TooComplex(tiles)) => too_complex.extend(tiles),
+            Err(error) => return Err(error),
+        }
+    }
+    if !too_complex.is_empty() {
+        return Err(ProbabilityError::TooComplex(too_complex));
+    }
+    let groups = groups_counted;
+
+    // `interior_weights[t]` is proportional to the number of ways to place the remaining mines on
+    // the interior when the frontier holds `t` mines
+    let max_frontier_mines = cells.len().min(remaining_mines);
+    let log_weights: Vec<Option<f64>> = (0..=max_frontier_mines)
+        .map(|frontier_mines| {
+            let interior_mines = remaining_mines - frontier_mines;
+            (interior_mines <= interior.len()).then(|| ln_choose(interior.len(), interior_mines))
+        })
+        .collect();
+    let max_log_weight = log_weights
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max);
+    if max_log_weight == f64::NEG_INFINITY {
+        return Err(ProbabilityError::Contradiction);
+    }
+    let interior_weights: Vec<f64> = log_weights
+        .iter()
+        .map(|log_weight| log_weight.map_or(0., |log_weight| (log_weight - max_log_weight).exp()))
+        .collect();
+    let interior_weight = |frontier_mines: usize| -> f64 {
+        interior_weights.get(frontier_mines).copied().unwrap_or(0.)
+    };
+
+    // The solutions of every group but one, by number of mines
+    let mut prefixes = vec![vec![1.]];
+    for group in groups.iter() {
+        prefixes.push(convolve(prefixes.last().unwrap(), &group.solutions));
+    }
+    let mut suffixes = vec![vec![1.]];
+    for group in groups.iter().rev() {
+        suffixes.push(convolve(suffixes.last().unwrap(), &group.solutions));
+    }
+    suffixes.reverse();
+
+    let total = prefixes.last().unwrap();
+    let normalization: f64 = total
+        .iter()
+        .enumerate()
+        .map(|(mines, solutions)| solutions * interior_weight(mines))
+        .sum();
+    if normalization <= 0. {
+        return Err(ProbabilityError::Contradiction);
+    }
+
+    let mut result = HashMap::new();
+    for (index, group) in groups.iter().enumerate() {
+        let others = convolve(&prefixes[index], &suffixes[index + 1]);
+        for (cell_index, coordinates) in group.cells.iter().enumerate() {
+            let weight: f64 = group
+                .cell_solutions
+                .iter()
+                .enumerate()
+                .map(|(group_mines, cell_solutions)| {
+                    let other_weight: f64 = others
+                        .iter()
+                        .enumerate()
+                        .map(|(other_mines, solutions)| {
+                            solutions * interior_weight(group_mines + other_mines)
+                        })
+                        .sum();
+                    cell_solutions[cell_index] * other_weight
+                })
+                .sum();
+            result.insert(*coordinates, weight / normalization);
+        }
+    }
+
+    if !interior.is_empty() {
+        let interior_mines: f64 = total
+            .iter()
+            .enumerate()
+            .filter(|(mines, _)| *mines <= remaining_mines)
+            .map(|(mines, solutions)| {
+                solutions * interior_weight(mines) * (remaining_mines - mines) as f64
+            })
+            .sum();
+        let probability = interior_mines / normalization / interior.len() as f64;
+        for coordinates in interior {
+            result.insert(coordinates, probability);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Splits the frontier cells into groups connected by shared constraints, returning the cell and
+/// constraint indices of each group
+fn groups(cells: &[Coordinates], constraints: &[Constraint]) -> Vec<(Vec<usize>, Vec<usize>)> {
+    let mut constraints_by_cell = vec![Vec::new(); cells.len()];
+    for (index, constraint) in constraints.iter().enumerate() {
+        for cell in constraint.cells.iter() {
+            constraints_by_cell[*cell].push(index);
+        }
+    }
+
+    let mut grouped = vec![false; cells.len()];
+    let mut result = Vec::new();
+
+    for first_cell in 0..cells.len() {
+        if grouped[first_cell] {
+            continue;
+        }
+
+        // Walking the group again from the last cell reached starts from one of its ends, so that
+        // counting sweeps it along a single edge of numbers
+        let (group_cells, _) = breadth_first(first_cell, constraints, &constraints_by_cell);
+        let last_cell = *group_cells.last().unwrap();
+        let group = breadth_first(last_cell, constraints, &constraints_by_cell);
+        for cell in group.0.iter() {
+            grouped[*cell] = true;
+        }
+        result.push(group);
+    }
+
+    result
+}
+
+/// Returns the cells connected to `first_cell` by shared constraints in breadth first order, and
+/// the constraints in the order they are reached
+fn breadth_first(
+    first_cell: usize,
+    constraints: &[Constraint],
+    constraints_by_cell: &[Vec<usize>],
+) -> (Vec<usize>, Vec<usize>) {
+    let mut visited_cells = vec![false; constraints_by_cell.len()];
+    let mut visited_constraints = vec![false; constraints.len()];
+    let mut group_cells = vec![first_cell];
+    let mut group_constraints = Vec::new();
+    visited_cells[first_cell] = true;
+
+    let mut next = 0;
+    while next < group_cells.len() {
+        let cell = group_cells[next];
+        next += 1;
+        for constraint in constraints_by_cell[cell].iter().copied() {
+            if visited_constraints[constraint] {
+                continue;
+            }
+            visited_constraints[constraint] = true;
+            group_constraints.push(constraint);
+            for other_cell in constraints[constraint].cells.iter().copied() {
+                if !visited_cells[other_cell] {
+                    visited_cells[other_cell] = true;
+                    group_cells.push(other_cell);
+                }
+            }
+        }
+    }
+
+    (group_cells, group_constraints)
+}
+
+/// Counts every mine assignment of a group which satisfies its constraints, by number of mines
+/// and for every cell.
+///
+/// The cells are assigned in order, and layer `l` holds the partial assignments of the first `l`
+/// cells. Two partial assignments placing the same mines around the open constraints, which have
+/// cells on both sides of the layer, are completed the same ways, so a layer only keeps the
+/// number of partial assignments of each state of the open constraints. A backward pass counts
+/// the completions of every state, and the assignments where a cell holds a mine are the partial
+/// assignments before it times the completions after it.
+fn count(
+    cells: &[Coordinates],
+    constraints: &[Constraint],
+    group_cells: Vec<usize>,
+    group_constraints: Vec<usize>,
+) -> Result<Group, ProbabilityError> {
+    let group_coordinates: Vec<Coordinates> = group_cells.iter().map(|cell| cells[*cell]).collect();
+    let cell_count = group_cells.len();
+    let mut position = vec![usize::MAX; cells.len()];
+    for (index, cell) in group_cells.iter().enumerate() {
+        position[*cell] = index;
+    }
+
+    let mut layout = Layout {
+        cell_constraints: vec![Vec::new(); cell_count],
+        targets: Vec::with_capacity(group_constraints.len()),
+        open: Vec::with_capacity(cell_count + 1),
+    };
+    let mut spans = Vec::with_capacity(group_constraints.len());
+    for (index, constraint) in group_constraints.iter().enumerate() {
+        let constraint = &constraints[*constraint];
+        let mut positions: Vec<usize> = constraint
+            .cells
+            .iter()
+            .map(|cell| position[*cell])
+            .collect();
+        positions.sort_unstable();
+        for (assigned, cell) in positions.iter().enumerate() {
+            layout.cell_constraints[*cell].push((index, positions.len() - assigned - 1));
+        }
+        spans.push((positions[0], positions[positions.len() - 1]));
+        layout.targets.push(constraint.mines as u8);
+    }
+    for layer in 0..=cell_count {
+        layout.open.push(
+            (0..spans.len())
+                .filter(|constraint| {
+                    let (first, last) = spans[*constraint];
+                    first < layer && last >= layer
+                })
+                .collect(),
+        );
+    }
+
+    // `layers[l][state][k]` is the number of assignments of the first `l` cells with `k` mines
+    let mut layers: Vec<HashMap<Vec<u8>, Vec<f64>>> = vec![HashMap::from([(Vec::new(), vec![1.])])];
+    for cell in 0..cell_count {
+        let mut next_layer: HashMap<Vec<u8>, Vec<f64>> = HashMap::new();
+        for (state, counts) in layers[cell].iter() {
+            for is_mine in [false, true] {
+                let Some(next_state) = layout.assign(cell, state, is_mine) else {
+                    continue;
+                };
+                let next_counts = next_layer
+                    .entry(next_state)
+                    .or_insert_with(|| vec![0.; cell + 2]);
+                for (mines, count) in counts.iter().enumerate() {
+                    next_counts[mines + is_mine as usize] += count;
+                }
+            }
+        }
+
+        if next_layer.is_empty() {
+            return Err(ProbabilityError::Contradiction);
+        }
+        if next_layer.len() > MAX_LAYER_STATES {
+            return Err(ProbabilityError::TooComplex(group_coordinates));
+        }
+        layers.push(next_layer);
+    }
+
+    // `completions[l][state][k]` is the number of assignments of the cells from `l` on with `k`
+    // mines which complete the state
+    let mut completions: Vec<HashMap<Vec<u8>, Vec<f64>>> = vec![HashMap::new(); cell_count + 1];
+    completions[cell_count] = layers[cell_count]
+        .keys()
+        .map(|state| (state.clone(), vec![1.]))
+        .collect();
+    for cell in (0..cell_count).rev() {
+        let mut layer_completions = HashMap::new();
+        for state in layers[cell].keys() {
+            let mut counts = vec![0.; cell_count - cell + 1];
+            for is_mine in [false, true] {
+                let next_counts = layout
+                    .assign(cell, state, is_mine)
+                    .and_then(|next_state| completions[cell + 1].get(&next_state));
+                for (mines, count) in next_counts.into_iter().flatten().enumerate() {
+                    counts[mines + is_mine as usize] += count;
+                }
+            }
+            layer_completions.insert(state.clone(), counts);
+        }
+        completions[cell] = layer_completions;
+    }
+
+    let solutions = completions[0].remove(&Vec::new()).unwrap_or_default();
+    if solutions.iter().all(|solutions| *solutions == 0.) {
+        return Err(ProbabilityError::Contradiction);
+    }
+
+    let mut cell_solutions = vec![vec![0.; cell_count]; cell_count + 1];
+    for cell in 0..cell_count {
+        for (state, counts) in layers[cell].iter() {
+            let Some(next_counts) = layout
+                .assign(cell, state, true)
+                .and_then(|next_state| completions[cell + 1].get(&next_state))
+            else {
+                continue;
+            };
+            for (mines, count) in counts.iter().enumerate() {
+                for (next_mines, next_count) in next_counts.iter().enumerate() {
+                    cell_solutions[mines + 1 + next_mines][cell] += count * next_count;
+                }
+            }
+        }
+    }
+
+    Ok(Group {
+        cells: group_coordinates,
+        solutions,
+        cell_solutions,
+    })
+}
+
+/// How the constraints of a group are laid out over its cells in the order they are counted
+struct Layout {
+    /// The constraints of every cell, with the number of their cells left to assign after it
+    cell_constraints: Vec<Vec<(usize, usize)>>,
+    /// The mines of every constraint
+    targets: Vec<u8>,
+    /// The constraints with cells on both sides of every layer, in increasing order. The state of
+    /// a layer is the number of mines assigned around each of them.
+    open: Vec<Vec<usize>>,
+}
+
+impl Layout {
+    /// Returns the state of the next layer when `cell` is assigned in `state`, `None` if a
+    /// constraint can no longer be satisfied
+    fn assign(&self, cell: usize, state: &[u8], is_mine: bool) -> Option<Vec<u8>> {
+        let mine = is_mine as u8;
+        for (constraint, unassigned) in self.cell_constraints[cell].iter().copied() {
+            let mines = self.mines(cell, state, constraint) + mine;
+            let target = self.targets[constraint];
+            if mines > target || mines as usize + unassigned < target as usize {
+                return None;
+            }
+        }
+
+        Some(
+            self.open[cell + 1]
+                .iter()
+                .map(|constraint| {
+                    let around_cell = self.cell_constraints[cell]
+                        .iter()
+                        .any(|(cell_constraint, _)| cell_constraint == constraint);
+                    self.mines(cell, state, *constraint) + if around_cell { mine } else { 0 }
+                })
+                .collect(),
+        )
+    }
+
+    /// The mines assigned around a constraint in a state of `layer`, none if it is not open yet
+    fn mines(&self, layer: usize, state: &[u8], constraint: usize) -> u8 {
+        match self.open[layer].binary_search(&constraint) {
+            Ok(index) => state[index],
+            Err(_) => 0,
+        }
+    }
+}
+
+fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut result = vec![0.; a.len() + b.len() - 1];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            result[i + j] += a * b;
+        }
+    }
+    result
+}
+
+/// The natural logarithm of the binomial coefficient `n` choose `k`
+fn ln_choose(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (1..=k)
+        .map(|i| ((n - k + i) as f64).ln() - (i as f64).ln())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    fn assert_probability(probabilities: &HashMap<Coordinates, f64>, x: u16, y: u16, p: f64) {
+        let probability = probabilities[&Coordinates { x, y }];
+        assert!(
+            (probability - p).abs() < 1e-9,
+            "({x}, {y}): {probability} != {p}"
+        );
+    }
+
+    /// A board whose revealed tiles show the numbers of the mines, every other tile covered
+    fn revealed_board(
+        grid: Grid,
+        mines: &[Coordinates],
+        revealed: impl Fn(Coordinates) -> bool,
+    ) -> VisibleBoard {
+        let mut board = VisibleBoard::covered(grid.clone(), mines.len() as u16);
+        for coordinates in grid.iter_coordinates().filter(|c| revealed(*c)) {
+            let number = grid
+                .iter_neighbors(coordinates)
+                .filter(|neighbor| mines.contains(neighbor))
+                .count();
+            board.set(coordinates, VisibleTile::Revealed(number as u8));
+        }
+        board
+    }
+
+    /// Computes the probabilities by trying every placement of the mines on the covered tiles
+    fn brute_force(board: &VisibleBoard) -> HashMap<Coordinates, f64> {
+        fn place(
+            board: &VisibleBoard,
+            covered: &[Coordinates],
+            mines: &mut Vec<Coordinates>,
+            mine_counts: &mut HashMap<Coordinates, f64>,
+            total: &mut f64,
+        ) {
+            if mines.len() == board.bomb_count() as usize {
+                let valid =
+                    board
+                        .iter_coordinates()
+                        .all(|coordinates| match board.get(coordinates) {
+                            VisibleTile::Revealed(number) => {
+                                board
+                                    .iter_neighbors(coordinates)
+                                    .filter(|neighbor| mines.contains(neighbor))
+                                    .count()
+                                    == number as usize
+                            }
+                            _ => true,
+                        });
+                if valid {
+                    *total += 1.;
+                    for mine in mines.iter() {
+                        *mine_counts.get_mut(mine).unwrap() += 1.;
+                    }
+                }
+                return;
+            }
+            for (index, coordinates) in covered.iter().enumerate() {
+                mines.push(*coordinates);
+                place(board, &covered[index + 1..], mines, mine_counts, total);
+                mines.pop();
+            }
+        }
+
+        let covered: Vec<Coordinates> = board
+            .iter_coordinates()
+            .filter(|coordinates| board.get(*coordinates).is_covered())
+            .collect();
+        let mut mine_counts = covered.iter().map(|c| (*c, 0.)).collect();
+        let mut total = 0.;
+        place(
+            board,
+            &covered,
+            &mut Vec::new(),
+            &mut mine_counts,
+            &mut total,
+        );
+        mine_counts
+            .into_iter()
+            .map(|(coordinates, count)| (coordinates, count / total))
+            .collect()
+    }
+
+    #[test]
+    fn frontier_and_interior_are_exact() {
+        // A 1 over the first two covered tiles, one mine left for the last one
+        let mut board = VisibleBoard::covered(Grid::new(4, 1), 2);
+        board.set(Coordinates { x: 0, y: 0 }, VisibleTile::Revealed(1));
+        let probabilities = mine_probabilities(&board).unwrap();
+
+        assert_probability(&probabilities, 1, 0, 1.);
+        assert_probability(&probabilities, 2, 0, 0.5);
+        assert_probability(&probabilities, 3, 0, 0.5);
+    }
+
+    #[test]
+    fn contradicting_flags_have_no_probabilities() {
+        let mut board = VisibleBoard::covered(Grid::new(3, 1), 2);
+        board.set(Coordinates { x: 1, y: 0 }, VisibleTile::Revealed(0));
+        board.set(Coordinates { x: 2, y: 0 }, VisibleTile::Flagged);
+
+        assert_eq!(
+            mine_probabilities(&board),
+            Err(ProbabilityError::Contradiction)
+        );
+    }
+
+    #[test]
+    fn groups_of_more_than_twenty_tiles_are_exact() {
+        // A revealed row between two covered rows makes a single group of 22 tiles, over a covered
+        // row of interior tiles
+        let mines =
+            [(0, 0), (3, 2), (4, 2), (8, 0), (10, 2), (5, 3)].map(|(x, y)| Coordinates { x, y });
+        let board = revealed_board(Grid::new(11, 4), &mines, |coordinates| coordinates.y == 1);
+        let probabilities = mine_probabilities(&board).unwrap();
+
+        let expected = brute_force(&board);
+        assert_eq!(probabilities.len(), expected.len());
+        for (coordinates, probability) in expected {
+            assert_probability(&probabilities, coordinates.x, coordinates.y, probability);
+        }
+    }
+
+    #[test]
+    fn long_frontiers_are_counted_whole() {
+        // A revealed row between two covered rows makes a single group of 50 tiles
+        let mines: Vec<Coordinates> = (0..8)
+            .map(|i| Coordinates {
+                x: 3 * i + 1,
+                y: if i % 2 == 0 { 0 } else { 2 },
+            })
+            .chain([(2, 4), (12, 3), (20, 4)].map(|(x, y)| Coordinates { x, y }))
+            .collect();
+        let board = revealed_board(Grid::new(25, 5), &mines, |coordinates| coordinates.y == 1);
+        let probabilities = mine_probabilities(&board).unwrap();
+
+        // Every covered tile has a probability, and they add up to the mine count
+        assert_eq!(probabilities.len(), 100);
+        let expected_mines: f64 = probabilities.values().sum();
+        assert!((expected_mines - mines.len() as f64).abs() < 1e-9);
+        // The numbers can not tell a tile above them from the tile below
+        for x in 0..25 {
+            let probability = probabilities[&Coordinates { x, y: 0 }];
+            assert!((0. ..=1.).contains(&probability));
+            assert_probability(&probabilities, x, 2, probability);
+        }
+    }
+
+    #[test]
+    fn entangled_frontiers_are_too_complex() {
+        // One revealed tile in four ties every covered tile to a single group, and each number
+        // leaves many ways to place its mines while it is open
+        let grid = Grid::new(60, 60);
+        let mines: Vec<Coordinates> = grid
+            .iter_coordinates()
+            .filter(|c| (c.x % 2 == 1 || c.y % 2 == 1) && (7 * c.x + 13 * c.y) % 5 == 0)
+            .collect();
+        let board = revealed_board(grid, &mines, |c| c.x % 2 == 0 && c.y % 2 == 0);
+
+        match mine_probabilities(&board) {
+            Err(ProbabilityError::TooComplex(tiles)) => assert_eq!(tiles.len(), 2700),
+            result => panic!("{:?}", result.map(|probabilities| probabilities.len())),
+        }
+    }
+}
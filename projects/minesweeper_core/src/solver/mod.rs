@@ -0,0 +1,19 @@
+mod count_unsolved_tiles;
+mod deduce;
+mod deductions;
+mod mine_probabilities;
+mod no_guess_layout;
+mod probability_error;
+mod unsolved_tiles;
+mod visible_board;
+mod visible_tile;
+
+pub use count_unsolved_tiles::*;
+pub use deduce::*;
+pub use deductions::*;
+pub use mine_probabilities::*;
+pub use no_guess_layout::*;
+pub use probability_error::*;
+pub use unsolved_tiles::*;
+pub use visible_board::*;
+pub use visible_tile::*;
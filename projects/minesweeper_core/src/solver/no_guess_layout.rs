@@ -0,0 +1,193 @@
+use crate::{solver::*, Coordinates, TileMap};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// The most bomb moves tried to repair a layout before generating another one
+const MAX_REPAIR_MOVES: u32 = 16;
+
+/// A tile map generated to be solvable without guessing
+#[derive(Debug, Clone)]
+pub struct NoGuessLayout {
+    pub tile_map: TileMap,
+    /// The number of layouts generated, including the accepted one
+    pub attempts: u32,
+    /// Is the tile map solvable without guessing. False when the attempt budget ran out.
+    pub solvable: bool,
+}
+
+impl NoGuessLayout {
+    /// Places bombs on the grid of `tile_map` until it is solvable from `start` without guessing,
+    /// trying at most `max_attempts` layouts, at least one. When the budget runs out, the layout
+    /// leaving the fewest safe tiles unsolved is used, the earliest one on ties, so the fallback
+    /// only depends on the seed.
+    ///
+    /// Each layout is locally repaired before giving up on it: a bomb next to the tiles the solver
+    /// is stuck on is moved to another tile, and the move is kept when fewer tiles are left
+    /// unsolved, up to `MAX_REPAIR_MOVES` tries.
+    ///
+    /// Each attempt uses a seed drawn from a `ChaCha8Rng` seeded with `seed`, which also seeds the
+    /// repairs of the attempt.
+    pub fn generate(
+        tile_map: &TileMap,
+        bomb_count: u16,
+        seed: u64,
+        start: Coordinates,
+        safe_tiles: &[Coordinates],
+        max_attempts: u32,
+    ) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut best: Option<(TileMap, usize)> = None;
+
+        for attempt in 1..=max_attempts.max(1) {
+            let attempt_seed = rng.gen();
+            let tile_map = tile_map
+                .clone()
+                .place_bombs(bomb_count, attempt_seed, safe_tiles);
+            let (tile_map, unsolved_tiles) = repair(tile_map, attempt_seed, start, safe_tiles);
+
+            if unsolved_tiles == 0 {
+                return Self {
+                    tile_map,
+                    attempts: attempt,
+                    solvable: true,
+                };
+            }
+
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_unsolved_tiles)| unsolved_tiles < *best_unsolved_tiles)
+            {
+                best = Some((tile_map, unsolved_tiles));
+            }
+        }
+
+        Self {
+            tile_map: best.unwrap().0,
+            attempts: max_attempts.max(1),
+            solvable: false,
+        }
+    }
+}
+
+/// Moves bombs away from the tiles the solver is stuck on while it leaves fewer tiles unsolved,
+/// returning the repaired map and the number of tiles it leaves unsolved
+fn repair(
+    mut tile_map: TileMap,
+    seed: u64,
+    start: Coordinates,
+    safe_tiles: &[Coordinates],
+) -> (TileMap, usize) {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut unsolved = unsolved_tiles(&tile_map, start);
+
+    for _ in 0..MAX_REPAIR_MOVES {
+        if unsolved.is_empty() {
+            break;
+        }
+
+        let bombs: Vec<Coordinates> = tile_map
+            .iter_coordinates()
+            .flat_map(|coordinates| (0..tile_map.bombs_at(coordinates)).map(move |_| coordinates))
+            .collect();
+        let stuck_bombs: Vec<usize> = (0..bombs.len())
+            .filter(|index| {
+                tile_map
+                    .iter_neighbors(bombs[*index])
+                    .any(|neighbor| unsolved.contains(&neighbor))
+            })
+            .collect();
+        let targets: Vec<Coordinates> = tile_map
+            .iter_coordinates()
+            .filter(|coordinates| !safe_tiles.contains(coordinates))
+            .filter(|coordinates| tile_map.bombs_at(*coordinates) < tile_map.max_tile_bombs())
+            .collect();
+        let (Some(moved), Some(target)) = (stuck_bombs.choose(&mut rng), targets.choose(&mut rng))
+        else {
+            break;
+        };
+
+        let mut moved_bombs = bombs.clone();
+        moved_bombs[*moved] = *target;
+        let moved_map = tile_map.clone().place_bombs_at(&moved_bombs);
+        let moved_unsolved = unsolved_tiles(&moved_map, start);
+        if moved_unsolved.len() < unsolved.len() {
+            tile_map = moved_map;
+            unsolved = moved_unsolved;
+        }
+    }
+
+    (tile_map, unsolved.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    fn opening(tile_map: &TileMap, start: Coordinates) -> Vec<Coordinates> {
+        std::iter::once(start)
+            .chain(tile_map.iter_neighbors(start))
+            .collect()
+    }
+
+    #[test]
+    fn generated_layouts_solve_from_the_opening() {
+        let tile_map = TileMap::empty(16, 16);
+        let start = Coordinates { x: 8, y: 8 };
+        let safe_tiles = opening(&tile_map, start);
+
+        for seed in 0..10 {
+            let layout = NoGuessLayout::generate(&tile_map, 40, seed, start, &safe_tiles, 1000);
+
+            assert!(layout.solvable);
+            assert_eq!(layout.tile_map.bomb_count(), 40);
+            assert!(safe_tiles
+                .iter()
+                .all(|coordinates| !layout.tile_map.is_bomb_at(*coordinates)));
+            assert_eq!(count_unsolved_tiles(&layout.tile_map, start), 0);
+            assert!((1..=1000).contains(&layout.attempts));
+        }
+    }
+
+    #[test]
+    fn attempts_are_reported() {
+        // The bomb is on either side of the start, which no deduction can tell apart
+        let tile_map = TileMap::empty(3, 1);
+        let start = Coordinates { x: 1, y: 0 };
+        let layout = NoGuessLayout::generate(&tile_map, 1, 7, start, &[start], 25);
+
+        assert!(!layout.solvable);
+        assert_eq!(layout.attempts, 25);
+        assert_eq!(count_unsolved_tiles(&layout.tile_map, start), 1);
+    }
+
+    #[test]
+    fn fallback_is_the_fewest_unsolved_tiles_and_deterministic() {
+        let tile_map = TileMap::empty(8, 8);
+        let start = Coordinates { x: 0, y: 0 };
+        let generate = || NoGuessLayout::generate(&tile_map, 24, 3, start, &[start], 5);
+        let layout = generate();
+
+        assert!(!layout.solvable);
+        assert_eq!(layout.attempts, 5);
+        assert_eq!(layout.tile_map.map(), generate().tile_map.map());
+
+        // Repairs never leave more unsolved tiles than the layouts they start from
+        let unsolved_tiles = count_unsolved_tiles(&layout.tile_map, start);
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for _ in 0..5 {
+            let attempt = tile_map.clone().place_bombs(24, rng.gen(), &[start]);
+            assert!(unsolved_tiles <= count_unsolved_tiles(&attempt, start));
+        }
+    }
+
+    #[test]
+    fn zero_attempts_are_clamped_to_one() {
+        let tile_map = TileMap::empty_grid(Grid::new(9, 9));
+        let start = Coordinates { x: 4, y: 4 };
+        let layout = NoGuessLayout::generate(&tile_map, 10, 1, start, &[start], 0);
+
+        assert_eq!(layout.attempts, 1);
+        assert_eq!(layout.tile_map.bomb_count(), 10);
+    }
+}
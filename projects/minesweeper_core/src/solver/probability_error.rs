@@ -0,0 +1,30 @@
+use crate::Coordinates;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Why the mine probabilities of a board can not be computed exactly
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProbabilityError {
+    /// The flags or the bomb count contradict the revealed numbers
+    Contradiction,
+    /// The mine assignments of these frontier tiles are too entangled to be counted. Every other
+    /// probability depends on them through the bomb count, so none is exact.
+    TooComplex(Vec<Coordinates>),
+}
+
+impl Display for ProbabilityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Contradiction => write!(f, "the flags or the bomb count contradict the numbers"),
+            Self::TooComplex(tiles) => write!(
+                f,
+                "the mine assignments of {} frontier tiles are too entangled to be counted",
+                tiles.len()
+            ),
+        }
+    }
+}
+
+impl Error for ProbabilityError {}
@@ -0,0 +1,49 @@
+use crate::{solver::*, Coordinates, Tile, TileMap};
+
+/// Plays `tile_map` from `start` using only logical deductions and returns the safe tiles left
+/// covered once no more deductions can be made, in left-to-right then bottom-to-top order
+pub fn unsolved_tiles(tile_map: &TileMap, start: Coordinates) -> Vec<Coordinates> {
+    let mut board = VisibleBoard::covered(tile_map.grid().clone(), tile_map.bomb_count());
+    reveal(&mut board, tile_map, start);
+
+    loop {
+        let deductions = deduce(&board);
+        if deductions.is_empty() {
+            break;
+        }
+
+        for coordinates in deductions.mines {
+            board.set(coordinates, VisibleTile::Flagged);
+        }
+        for coordinates in deductions.safe {
+            reveal(&mut board, tile_map, coordinates);
+        }
+    }
+
+    board
+        .iter_coordinates()
+        .filter(|coordinates| board.get(*coordinates).is_covered())
+        .filter(|coordinates| !tile_map.is_bomb_at(*coordinates))
+        .collect()
+}
+
+/// Reveals a tile, propagating the reveal through empty tiles
+fn reveal(board: &mut VisibleBoard, tile_map: &TileMap, coordinates: Coordinates) {
+    let mut stack = vec![coordinates];
+    while let Some(coordinates) = stack.pop() {
+        if !board.get(coordinates).is_covered() {
+            continue;
+        }
+
+        let number = match tile_map.map()[coordinates.y as usize][coordinates.x as usize] {
+            Tile::Bomb(_) | Tile::Void => continue,
+            Tile::BombNeighbor(v) => v,
+            Tile::Empty => 0,
+        };
+        board.set(coordinates, VisibleTile::Revealed(number));
+
+        if number == 0 {
+            stack.extend(board.iter_neighbors(coordinates));
+        }
+    }
+}
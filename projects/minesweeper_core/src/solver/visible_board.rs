@@ -0,0 +1,114 @@
+use crate::{solver::VisibleTile, Coordinates, Game, Grid, Tile, TileMap, TileState};
+
+/// The state of a board as seen by the player
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VisibleBoard {
+    bomb_count: u16,
+    grid: Grid,
+    // Stores tiles in left-to-right then bottom-to-top layout
+    tiles: Vec<VisibleTile>,
+}
+
+impl VisibleBoard {
+    /// Creates a board where every tile of the grid is covered
+    pub fn covered(grid: Grid, bomb_count: u16) -> Self {
+        Self {
+            bomb_count,
+            tiles: vec![VisibleTile::Covered; grid.area()],
+            grid,
+        }
+    }
+
+    /// Creates the board seen by the player from the covered and flagged tiles of a `Game`
+    pub fn from_game(game: &Game) -> Self {
+        Self::from_tile_states(game.tile_map(), |coordinates| {
+            game.tile_state(coordinates).unwrap_or_default()
+        })
+    }
+
+    /// Creates the board seen by the player from the covered tiles of a `Game`, treating flagged
+    /// tiles as covered so that wrong flags can not mislead deductions
+    pub fn from_game_ignoring_flags(game: &Game) -> Self {
+        Self::from_tile_states(game.tile_map(), |coordinates| {
+            match game.tile_state(coordinates).unwrap_or_default() {
+                TileState::Flagged => TileState::Covered,
+                tile_state => tile_state,
+            }
+        })
+    }
+
+    /// Creates the board seen by the player from the state of every tile of a tile map
+    pub fn from_tile_states(
+        tile_map: &TileMap,
+        tile_state: impl Fn(Coordinates) -> TileState,
+    ) -> Self {
+        let mut result = Self::covered(tile_map.grid().clone(), tile_map.bomb_count());
+
+        for (y, tile_row) in tile_map.map().iter().enumerate() {
+            for (x, tile) in tile_row.iter().enumerate() {
+                if *tile == Tile::Void {
+                    continue;
+                }
+                let coordinates = Coordinates {
+                    x: x as u16,
+                    y: y as u16,
+                };
+
+                let visible_tile = match tile_state(coordinates) {
+                    TileState::Flagged => VisibleTile::Flagged,
+                    TileState::Covered => VisibleTile::Covered,
+                    TileState::Revealed => match tile {
+                        Tile::Bomb(_) => VisibleTile::Flagged,
+                        Tile::BombNeighbor(v) => VisibleTile::Revealed(*v),
+                        Tile::Empty | Tile::Void => VisibleTile::Revealed(0),
+                    },
+                };
+                result.set(coordinates, visible_tile);
+            }
+        }
+
+        result
+    }
+
+    pub fn width(&self) -> u16 {
+        self.grid.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.grid.height
+    }
+
+    pub fn bomb_count(&self) -> u16 {
+        self.bomb_count
+    }
+
+    /// Returns the tile at the coordinates, which must be in bounds
+    pub fn get(&self, coordinates: Coordinates) -> VisibleTile {
+        self.tiles[self.grid.index(coordinates)]
+    }
+
+    /// Replaces the tile at the coordinates, which must be in bounds
+    pub fn set(&mut self, coordinates: Coordinates, tile: VisibleTile) {
+        let index = self.grid.index(coordinates);
+        self.tiles[index] = tile;
+    }
+
+    /// Returns true if the coordinates are a tile of the board; otherwise false
+    pub fn in_bounds(&self, coordinates: Coordinates) -> bool {
+        self.grid.in_bounds(coordinates)
+    }
+
+    /// Returns every coordinate of the tiles of the board in left-to-right then bottom-to-top
+    /// order
+    pub fn iter_coordinates(&self) -> impl Iterator<Item = Coordinates> + '_ {
+        self.grid.iter_coordinates()
+    }
+
+    /// Returns the in bounds neighbor coordinates of the coordinates
+    pub fn iter_neighbors(
+        &self,
+        coordinates: Coordinates,
+    ) -> impl Iterator<Item = Coordinates> + '_ {
+        self.grid.iter_neighbors(coordinates)
+    }
+}
@@ -0,0 +1,18 @@
+/// A tile as seen by the player
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VisibleTile {
+    /// A covered tile which is not flagged
+    Covered,
+
+    /// A covered tile which is flagged, or a revealed bomb
+    Flagged,
+
+    /// A revealed tile showing the number of neighbor bombs
+    Revealed(u8),
+}
+
+impl VisibleTile {
+    pub const fn is_covered(&self) -> bool {
+        matches!(self, Self::Covered)
+    }
+}
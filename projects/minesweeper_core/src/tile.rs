@@ -0,0 +1,46 @@
+#[cfg(feature = "debug")]
+use colored::Colorize;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+pub enum Tile {
+    /// A tile holding this many bombs, at least one
+    Bomb(u8),
+    /// A tile whose neighbors hold this many bombs
+    BombNeighbor(u8),
+    Empty,
+    /// A cell of the map which is not a tile, see `Mask`
+    Void,
+}
+
+impl Tile {
+    pub const fn is_bomb(&self) -> bool {
+        matches!(self, Self::Bomb(_))
+    }
+
+    /// The number of bombs the tile holds
+    pub const fn bomb_count(&self) -> u8 {
+        match self {
+            Self::Bomb(count) => *count,
+            _ => 0,
+        }
+    }
+
+    #[cfg(feature = "debug")]
+    pub fn console_output(&self) -> String {
+        format!(
+            "{}",
+            match self {
+                Tile::Bomb(_) => "*".bright_red(),
+                Tile::BombNeighbor(v) => match v {
+                    1 => "1".cyan(),
+                    2 => "2".green(),
+                    3 => "3".yellow(),
+                    _ => v.to_string().red(),
+                },
+                Tile::Empty => " ".normal(),
+                Tile::Void => "#".dimmed(),
+            }
+        )
+    }
+}
@@ -0,0 +1,564 @@
+use crate::{Coordinates, Grid, Mask, Neighborhood, Tile, TileMapAnalysis, Topology, Wrap};
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// A grid of tiles play area.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+pub struct TileMap {
+    bomb_count: u16,
+    // The tiles holding bombs, counted as they are placed since wins are checked against it
+    bomb_tile_count: usize,
+    max_tile_bombs: u8,
+    grid: Grid,
+    // Stores tiles in left-to-right then bottom-to-top layout
+    map: Vec<Vec<Tile>>,
+}
+
+impl TileMap {
+    pub fn empty(width: u16, height: u16) -> Self {
+        Self::empty_grid(Grid::new(width, height))
+    }
+
+    /// Creates a new `TileMap` without bombs on the tiles of a grid, its void cells are
+    /// `Tile::Void`
+    pub fn empty_grid(grid: Grid) -> Self {
+        let map = (0..grid.height)
+            .map(|y| {
+                (0..grid.width)
+                    .map(|x| {
+                        if grid.in_bounds(Coordinates { x, y }) {
+                            Tile::Empty
+                        } else {
+                            Tile::Void
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            bomb_count: 0,
+            bomb_tile_count: 0,
+            max_tile_bombs: 1,
+            grid,
+            map,
+        }
+    }
+
+    /// Lets a tile hold up to `max_tile_bombs` bombs, at least one. Must be called before the
+    /// bombs are placed.
+    pub fn with_max_tile_bombs(self, max_tile_bombs: u8) -> Self {
+        Self {
+            max_tile_bombs: max_tile_bombs.max(1),
+            ..self
+        }
+    }
+
+    /// Lays the tiles out with another topology, numbering them again
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.grid.topology = topology;
+        self.place_bomb_neighbors();
+        self
+    }
+
+    /// Makes edges touch the opposite edge, numbering the tiles again
+    pub fn with_wrap(mut self, wrap: Wrap) -> Self {
+        self.grid.wrap = wrap;
+        self.place_bomb_neighbors();
+        self
+    }
+
+    /// Counts the bombs of other neighbors, numbering the tiles again
+    pub fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.grid.neighborhood = neighborhood;
+        self.place_bomb_neighbors();
+        self
+    }
+
+    /// Shapes the map like the mask, dropping the bombs on its void cells and numbering the tiles
+    /// again
+    pub fn with_mask(self, mask: Mask) -> Self {
+        let bombs: Vec<Coordinates> = self
+            .iter_coordinates()
+            .filter(|coordinates| mask.contains(*coordinates))
+            .flat_map(|coordinates| (0..self.bombs_at(coordinates)).map(move |_| coordinates))
+            .collect();
+        let grid = Grid {
+            mask: Some(mask),
+            ..self.grid.clone()
+        };
+        Self { grid, ..self }.place_bombs_at(&bombs)
+    }
+
+    /// Creates a new `TileMap` with the specified bomb count using a random seed.
+    pub fn new_with_bombs(width: u16, height: u16, bomb_count: u16) -> Self {
+        Self::new_with_bombs_seeded(width, height, bomb_count, Self::random_seed())
+    }
+
+    /// Creates a new `TileMap` with the specified bomb count. The same seed always produces the
+    /// same map, on every platform.
+    pub fn new_with_bombs_seeded(width: u16, height: u16, bomb_count: u16, seed: u64) -> Self {
+        Self::new_with_safe_tiles(width, height, bomb_count, seed, &[])
+    }
+
+    /// Creates a new `TileMap` with the specified bomb count where none of the `safe_tiles` hold a
+    /// bomb, see `place_bombs`
+    pub fn new_with_safe_tiles(
+        width: u16,
+        height: u16,
+        bomb_count: u16,
+        seed: u64,
+        safe_tiles: &[Coordinates],
+    ) -> Self {
+        Self::empty(width, height).place_bombs(bomb_count, seed, safe_tiles)
+    }
+
+    /// Replaces the bombs of the map with the specified bomb count where none of the `safe_tiles`
+    /// hold a bomb. The same grid, seed and safe tiles always produce the same map, on every
+    /// platform.
+    ///
+    /// Bombs are placed by a partial Fisher-Yates shuffle of the remaining tile indices
+    /// (left-to-right then bottom-to-top) driven by a `ChaCha8Rng` seeded with `seed`. Only `u32`
+    /// ranges are sampled so the result does not depend on the width of `usize`. When a tile can
+    /// hold several bombs, the indices are repeated once per bomb it can hold.
+    pub fn place_bombs(self, bomb_count: u16, seed: u64, safe_tiles: &[Coordinates]) -> Self {
+        let width = self.grid.width as u32;
+        let area = self.grid.area() as u32;
+        let mut result = self.without_bombs();
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        // Place bombs
+        let tile_indices: Vec<u32> = (0..area)
+            .filter(|index| {
+                let coordinates = Coordinates {
+                    x: (index % width) as u16,
+                    y: (index / width) as u16,
+                };
+                result.in_bounds(coordinates) && !safe_tiles.contains(&coordinates)
+            })
+            .collect();
+        let mut indices: Vec<u32> = (0..result.max_tile_bombs)
+            .flat_map(|_| tile_indices.iter().copied())
+            .collect();
+        let bomb_count = (bomb_count as usize).min(indices.len()) as u16;
+
+        for i in 0..bomb_count as usize {
+            let j = rng.gen_range(i as u32..indices.len() as u32) as usize;
+            indices.swap(i, j);
+
+            let index = indices[i];
+            let (x, y) = (index % width, index / width);
+            result.add_bomb(Coordinates {
+                x: x as u16,
+                y: y as u16,
+            });
+        }
+
+        result.place_bomb_neighbors();
+        result
+    }
+
+    /// Creates a new `TileMap` with bombs at the given coordinates, which must be tiles. A tile
+    /// holds a single bomb since the map is created with one bomb per tile, see `place_bombs_at`.
+    pub fn new_with_bombs_at(width: u16, height: u16, bombs: &[Coordinates]) -> Self {
+        Self::empty(width, height).place_bombs_at(bombs)
+    }
+
+    /// Replaces the bombs of the map with bombs at the given coordinates, which must be tiles. A
+    /// tile holds a bomb each time it is given, up to the most bombs it can hold.
+    pub fn place_bombs_at(self, bombs: &[Coordinates]) -> Self {
+        let mut result = self.without_bombs();
+        for bomb in bombs {
+            if result.bombs_at(*bomb) < result.max_tile_bombs {
+                result.add_bomb(*bomb);
+            }
+        }
+
+        result.place_bomb_neighbors();
+        result
+    }
+
+    /// Removes the bombs and the numbers of the map, keeping its grid and the most bombs a tile
+    /// can hold
+    fn without_bombs(self) -> Self {
+        Self {
+            max_tile_bombs: self.max_tile_bombs,
+            ..Self::empty_grid(self.grid)
+        }
+    }
+
+    /// Adds a bomb to the tile at the coordinates
+    fn add_bomb(&mut self, coordinates: Coordinates) {
+        let tile = &mut self.map[coordinates.y as usize][coordinates.x as usize];
+        if !tile.is_bomb() {
+            self.bomb_tile_count += 1;
+        }
+        *tile = Tile::Bomb(tile.bomb_count() + 1);
+        self.bomb_count += 1;
+    }
+
+    /// Numbers the tiles around the bombs
+    fn place_bomb_neighbors(&mut self) {
+        for y in 0..self.grid.height {
+            for x in 0..self.grid.width {
+                let current = Coordinates { x, y };
+                if self.is_bomb_at(current) || !self.in_bounds(current) {
+                    continue;
+                }
+
+                let bomb_count = self.bomb_count_at(current);
+                let tile = &mut self.map[y as usize][x as usize];
+                *tile = if bomb_count == 0 {
+                    Tile::Empty
+                } else {
+                    Tile::BombNeighbor(bomb_count)
+                };
+            }
+        }
+    }
+
+    /// Generates a seed suitable for `new_with_bombs_seeded`
+    pub fn random_seed() -> u64 {
+        thread_rng().gen()
+    }
+
+    pub fn width(&self) -> u16 {
+        self.grid.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.grid.height
+    }
+
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// The number of bombs, which is more than the number of tiles holding them when a tile
+    /// holds several
+    pub fn bomb_count(&self) -> u16 {
+        self.bomb_count
+    }
+
+    /// The most bombs a tile can hold
+    pub fn max_tile_bombs(&self) -> u8 {
+        self.max_tile_bombs
+    }
+
+    /// The number of tiles holding bombs
+    pub fn bomb_tile_count(&self) -> usize {
+        self.bomb_tile_count
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.grid.topology
+    }
+
+    pub fn wrap(&self) -> Wrap {
+        self.grid.wrap
+    }
+
+    pub fn neighborhood(&self) -> &Neighborhood {
+        &self.grid.neighborhood
+    }
+
+    pub fn mask(&self) -> Option<&Mask> {
+        self.grid.mask.as_ref()
+    }
+
+    pub fn map(&self) -> &Vec<Vec<Tile>> {
+        &self.map
+    }
+
+    #[cfg(feature = "debug")]
+    pub fn console_output(&self) -> String {
+        let mut buffer = format!(
+            "Map ({}, {}) with {} bombs: \n",
+            self.grid.width, self.grid.height, self.bomb_count
+        );
+        let line: String = (0..=(self.grid.width + 1)).map(|_| '-').collect();
+        buffer = format!("{}{}\n", buffer, line);
+
+        for line in self.map.iter().rev() {
+            buffer = format!("{}|", buffer);
+            for tile in line.iter() {
+                buffer = format!("{}{}", buffer, tile.console_output());
+            }
+            buffer = format!("{}|\n", buffer);
+        }
+
+        format!("{}{}", buffer, line)
+    }
+
+    /// Returns the neighbor tiles of the coordinates, see `Grid::iter_neighbors`
+    pub fn iter_neighbors(
+        &self,
+        coordinates: Coordinates,
+    ) -> impl Iterator<Item = Coordinates> + '_ {
+        self.grid.iter_neighbors(coordinates)
+    }
+
+    /// Returns true if the coordinates are a tile of the map; otherwise false
+    pub fn in_bounds(&self, coordinates: Coordinates) -> bool {
+        self.grid.in_bounds(coordinates)
+    }
+
+    /// Returns true if there as a bomb at the coordinates; otherwise false
+    pub fn is_bomb_at(&self, coordinates: Coordinates) -> bool {
+        if !self.in_bounds(coordinates) {
+            return false;
+        }
+
+        self.map[coordinates.y as usize][coordinates.x as usize].is_bomb()
+    }
+
+    /// Returns the number of bombs the tile at the coordinates holds
+    pub fn bombs_at(&self, coordinates: Coordinates) -> u8 {
+        if !self.in_bounds(coordinates) {
+            return 0;
+        }
+
+        self.map[coordinates.y as usize][coordinates.x as usize].bomb_count()
+    }
+
+    /// Returns the 3BV of the map, see `analyze`
+    pub fn three_bv(&self) -> u32 {
+        self.analyze().three_bv
+    }
+
+    /// Measures how hard the map is to clear. Generators can use it to keep the maps of a
+    /// difficulty only.
+    pub fn analyze(&self) -> TileMapAnalysis {
+        let index = |coordinates: Coordinates| self.grid.index(coordinates);
+        let tile_at =
+            |coordinates: Coordinates| self.map[coordinates.y as usize][coordinates.x as usize];
+        let mut cleared = vec![false; self.grid.area()];
+        let mut analysis = TileMapAnalysis::default();
+
+        // An opening and the numbers around it are cleared by a single click
+        for start in self.iter_coordinates() {
+            if tile_at(start) != Tile::Empty || cleared[index(start)] {
+                continue;
+            }
+
+            analysis.openings += 1;
+            cleared[index(start)] = true;
+            let mut stack = vec![start];
+            while let Some(coordinates) = stack.pop() {
+                if tile_at(coordinates) != Tile::Empty {
+                    continue;
+                }
+                for neighbor in self.iter_neighbors(coordinates) {
+                    if !cleared[index(neighbor)] {
+                        cleared[index(neighbor)] = true;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+        }
+
+        // Every other number needs its own click, touching numbers form an island
+        let mut isolated_numbers = 0;
+        for start in self.iter_coordinates() {
+            if tile_at(start).is_bomb() || cleared[index(start)] {
+                continue;
+            }
+
+            analysis.islands += 1;
+            cleared[index(start)] = true;
+            let mut stack = vec![start];
+            while let Some(coordinates) = stack.pop() {
+                isolated_numbers += 1;
+                for neighbor in self.iter_neighbors(coordinates) {
+                    if !cleared[index(neighbor)] && !tile_at(neighbor).is_bomb() {
+                        cleared[index(neighbor)] = true;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+        }
+
+        analysis.three_bv = analysis.openings + isolated_numbers;
+        analysis
+    }
+
+    /// Returns every coordinates of the tiles of the map in left-to-right then bottom-to-top order
+    pub fn iter_coordinates(&self) -> impl Iterator<Item = Coordinates> + '_ {
+        self.grid.iter_coordinates()
+    }
+
+    // Returns the number of bombs held by the neighbors of the specified coordinates
+    pub fn bomb_count_at(&self, coordinates: Coordinates) -> u8 {
+        if self.is_bomb_at(coordinates) {
+            return 0;
+        }
+
+        self.iter_neighbors(coordinates)
+            .fold(0, |count, coord| count.saturating_add(self.bombs_at(coord)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(x: u16, y: u16) -> Coordinates {
+        Coordinates { x, y }
+    }
+
+    #[test]
+    fn seeded_placement_places_every_bomb_on_tiles_holding_several() {
+        for seed in 0..20 {
+            let tile_map =
+                TileMap::empty(4, 4)
+                    .with_max_tile_bombs(3)
+                    .place_bombs(30, seed, &[c(0, 0)]);
+
+            let bombs: u16 = tile_map
+                .iter_coordinates()
+                .map(|coordinates| tile_map.bombs_at(coordinates) as u16)
+                .sum();
+            assert_eq!(bombs, 30);
+            assert_eq!(tile_map.bomb_count(), 30);
+            assert_eq!(
+                tile_map.bomb_tile_count(),
+                tile_map
+                    .iter_coordinates()
+                    .filter(|coordinates| tile_map.is_bomb_at(*coordinates))
+                    .count()
+            );
+            assert!(tile_map
+                .iter_coordinates()
+                .all(|coordinates| tile_map.bombs_at(coordinates) <= 3));
+            assert!(!tile_map.is_bomb_at(c(0, 0)));
+        }
+    }
+
+    #[test]
+    fn numbers_add_up_the_bombs_of_every_neighbor() {
+        let tile_map = TileMap::empty(3, 2)
+            .with_max_tile_bombs(3)
+            .place_bombs_at(&[c(0, 0), c(0, 0), c(0, 0), c(2, 0), c(2, 0)]);
+
+        assert_eq!(tile_map.bomb_count(), 5);
+        assert_eq!(tile_map.bomb_tile_count(), 2);
+        assert_eq!(tile_map.map()[0][1], Tile::BombNeighbor(5));
+        assert_eq!(tile_map.map()[1][0], Tile::BombNeighbor(3));
+        assert_eq!(tile_map.map()[1][2], Tile::BombNeighbor(2));
+
+        // Extra bombs past the most a tile can hold are dropped
+        let tile_map = TileMap::empty(3, 1)
+            .with_max_tile_bombs(2)
+            .place_bombs_at(&[c(0, 0), c(0, 0), c(0, 0)]);
+        assert_eq!(tile_map.bombs_at(c(0, 0)), 2);
+        assert_eq!(tile_map.bomb_count(), 2);
+    }
+
+    fn analysis(three_bv: u32, openings: u32, islands: u32) -> TileMapAnalysis {
+        TileMapAnalysis {
+            three_bv,
+            openings,
+            islands,
+        }
+    }
+
+    #[test]
+    fn a_single_opening_clears_the_map() {
+        let tile_map = TileMap::new_with_bombs_at(3, 3, &[c(0, 0)]);
+        assert_eq!(tile_map.analyze(), analysis(1, 1, 0));
+
+        // The numbers along the opening are cleared with it
+        let tile_map = TileMap::new_with_bombs_at(5, 1, &[c(0, 0)]);
+        assert_eq!(tile_map.analyze(), analysis(1, 1, 0));
+    }
+
+    #[test]
+    fn numbers_away_from_openings_need_a_click_each() {
+        // 1, bomb, 2, bomb, 1: three islands of one number
+        let tile_map = TileMap::new_with_bombs_at(5, 1, &[c(1, 0), c(3, 0)]);
+        assert_eq!(tile_map.analyze(), analysis(3, 0, 3));
+
+        // Bomb, 1, 1, bomb: two touching numbers are one island of two clicks
+        let tile_map = TileMap::new_with_bombs_at(4, 1, &[c(0, 0), c(3, 0)]);
+        assert_eq!(tile_map.analyze(), analysis(2, 0, 1));
+    }
+
+    #[test]
+    fn an_opening_inside_another_counts_once_each() {
+        // A ring of bombs two tiles from the middle of a 9x9 map: the middle opens inside the
+        // ring, the border opens outside of it, and every number borders one of them
+        let ring: Vec<Coordinates> = Grid::new(9, 9)
+            .iter_coordinates()
+            .filter(|coordinates| {
+                let (x, y) = (coordinates.x.abs_diff(4), coordinates.y.abs_diff(4));
+                x.max(y) == 2
+            })
+            .collect();
+        let tile_map = TileMap::new_with_bombs_at(9, 9, &ring);
+
+        assert_eq!(ring.len(), 16);
+        assert_eq!(tile_map.analyze(), analysis(2, 2, 0));
+        assert_eq!(tile_map.three_bv(), 2);
+    }
+
+    #[test]
+    fn openings_and_islands_add_up() {
+        // An opening on the left, then an island of two numbers between two walls of bombs
+        //   . 2 B 4 B
+        //   . 2 B 4 B
+        let bombs = [c(2, 0), c(2, 1), c(4, 0), c(4, 1)];
+        let tile_map = TileMap::new_with_bombs_at(5, 2, &bombs);
+        assert_eq!(tile_map.map()[0][3], Tile::BombNeighbor(4));
+        assert_eq!(tile_map.analyze(), analysis(3, 1, 1));
+    }
+
+    fn bombs(tile_map: &TileMap) -> Vec<Coordinates> {
+        tile_map
+            .iter_coordinates()
+            .filter(|coordinates| tile_map.is_bomb_at(*coordinates))
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_places_the_same_bombs() {
+        for seed in [0, 1, 42, u64::MAX] {
+            let tile_map = TileMap::new_with_bombs_seeded(16, 16, 40, seed);
+            let again = TileMap::new_with_bombs_seeded(16, 16, 40, seed);
+
+            assert_eq!(tile_map.map(), again.map());
+            assert_eq!(bombs(&tile_map).len(), 40);
+        }
+    }
+
+    #[test]
+    fn other_seeds_place_other_bombs() {
+        let layouts: Vec<Vec<Coordinates>> = (0..10)
+            .map(|seed| bombs(&TileMap::new_with_bombs_seeded(16, 16, 40, seed)))
+            .collect();
+        for (index, layout) in layouts.iter().enumerate() {
+            assert!(layouts[index + 1..].iter().all(|other| other != layout));
+        }
+    }
+
+    #[test]
+    fn seeded_layouts_do_not_change_across_platforms_or_versions() {
+        // Pinned so that a change of the generator, which would change every saved seed, is
+        // noticed
+        let tile_map = TileMap::new_with_bombs_seeded(9, 9, 10, 42);
+        let pinned = [
+            (1, 0),
+            (4, 1),
+            (0, 2),
+            (2, 3),
+            (1, 4),
+            (8, 5),
+            (1, 6),
+            (7, 6),
+            (0, 7),
+            (5, 8),
+        ]
+        .map(|(x, y)| c(x, y));
+        assert_eq!(bombs(&tile_map), pinned);
+    }
+}
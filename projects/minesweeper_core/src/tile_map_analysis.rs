@@ -0,0 +1,15 @@
+/// How hard a tile map is to clear
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TileMapAnalysis {
+    /// The minimum number of clicks clearing the map: one per opening and one per number which
+    /// does not border an opening
+    pub three_bv: u32,
+
+    /// The groups of empty tiles revealed together
+    pub openings: u32,
+
+    /// The groups of touching numbers which do not border an opening
+    pub islands: u32,
+}
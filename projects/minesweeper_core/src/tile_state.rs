@@ -0,0 +1,9 @@
+/// What the player knows about a tile
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+pub enum TileState {
+    #[default]
+    Covered,
+    Flagged,
+    Revealed,
+}
@@ -0,0 +1,106 @@
+/// How the tiles of a map are laid out, which decides the neighbors of a tile
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Topology {
+    /// Square tiles in a grid, with 8 neighbors
+    #[default]
+    Square,
+    /// Hexagonal tiles in rows, every odd row shifted right by half a tile, with 6 neighbors
+    Hex,
+}
+
+impl Topology {
+    /// The offset coordinates which can be added to square coordinates to find their neighbors
+    /// in the following order:
+    /// 1. Bottom Left
+    /// 2. Bottom Center
+    /// 3. Bottom Right
+    /// 4. Middle Left
+    /// 5. Middle Right
+    /// 6. Top Left
+    /// 7. Top Center
+    /// 8. Top Right
+    const SQUARE_NEIGHBOR_OFFSETS: [(i8, i8); 8] = [
+        // Bottom
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+        // Middle
+        (-1, 0),
+        (1, 0),
+        // Top
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+    ];
+
+    /// The offset coordinates of the neighbors of hex coordinates on an even row, bottom then
+    /// middle then top
+    const EVEN_ROW_HEX_NEIGHBOR_OFFSETS: [(i8, i8); 6] =
+        [(-1, -1), (0, -1), (-1, 0), (1, 0), (-1, 1), (0, 1)];
+
+    /// The offset coordinates of the neighbors of hex coordinates on an odd row, bottom then middle
+    /// then top
+    const ODD_ROW_HEX_NEIGHBOR_OFFSETS: [(i8, i8); 6] =
+        [(0, -1), (1, -1), (-1, 0), (1, 0), (0, 1), (1, 1)];
+
+    /// Returns the offset coordinates which can be added to the coordinates of a tile to find its
+    /// neighbors, bottom then middle then top and left to right
+    pub fn neighbor_offsets(self, y: u16) -> &'static [(i8, i8)] {
+        match self {
+            Self::Square => &Self::SQUARE_NEIGHBOR_OFFSETS,
+            Self::Hex if y.is_multiple_of(2) => &Self::EVEN_ROW_HEX_NEIGHBOR_OFFSETS,
+            Self::Hex => &Self::ODD_ROW_HEX_NEIGHBOR_OFFSETS,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coordinates, Grid};
+
+    fn c(x: u16, y: u16) -> Coordinates {
+        Coordinates { x, y }
+    }
+
+    fn hex_grid() -> Grid {
+        Grid {
+            topology: Topology::Hex,
+            ..Grid::new(5, 5)
+        }
+    }
+
+    fn neighbors(grid: &Grid, coordinates: Coordinates) -> Vec<Coordinates> {
+        let mut neighbors: Vec<Coordinates> = grid.iter_neighbors(coordinates).collect();
+        neighbors.sort();
+        neighbors
+    }
+
+    #[test]
+    fn hex_neighbors_lean_left_on_even_rows() {
+        assert_eq!(
+            neighbors(&hex_grid(), c(2, 2)),
+            [c(1, 1), c(1, 2), c(1, 3), c(2, 1), c(2, 3), c(3, 2)]
+        );
+    }
+
+    #[test]
+    fn hex_neighbors_lean_right_on_odd_rows() {
+        assert_eq!(
+            neighbors(&hex_grid(), c(2, 1)),
+            [c(1, 1), c(2, 0), c(2, 2), c(3, 0), c(3, 1), c(3, 2)]
+        );
+    }
+
+    #[test]
+    fn hex_tiles_are_neighbors_of_their_neighbors() {
+        let grid = hex_grid();
+        for coordinates in grid.iter_coordinates() {
+            for neighbor in grid.iter_neighbors(coordinates) {
+                assert!(grid.iter_neighbors(neighbor).any(|n| n == coordinates));
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+/// Which edges of a map touch the opposite edge, so that the tiles along them are neighbors
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Wrap {
+    #[default]
+    None,
+    /// The left and right edges touch
+    Horizontal,
+    /// The bottom and top edges touch
+    Vertical,
+    /// The map is a torus
+    Both,
+}
+
+impl Wrap {
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Horizontal | Self::Both)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Vertical | Self::Both)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
     components::*,
-    events::{BoardCompletedEvent, BombExplosionEvent, TileMarkEvent, TileTriggerEvent},
+    events::{
+        BoardCompletedEvent, BoardOutcomeEvent, BombExplosionEvent, TileMarkEvent, TileTriggerEvent,
+    },
     resources::*,
     systems::*,
     util::*,
@@ -29,6 +31,9 @@ impl<T: States> Plugin for BoardPlugin<T> {
                 touch_input::<_, TouchInputTouchInputDeps>,
                 read_tile_trigger_event,
                 mark_tiles,
+                apply_board_outcomes
+                    .after(read_tile_trigger_event)
+                    .after(mark_tiles),
                 uncover_tiles,
             )
                 .run_if(in_state(self.running_state.clone())),
@@ -42,6 +47,7 @@ impl<T: States> Plugin for BoardPlugin<T> {
         );
 
         app.add_event::<BoardCompletedEvent>();
+        app.add_event::<BoardOutcomeEvent>();
         app.add_event::<BombExplosionEvent>();
         app.add_event::<TileMarkEvent>();
         app.add_event::<TileTriggerEvent>();
@@ -65,13 +71,37 @@ impl<T> BoardPlugin<T> {
 
         let board_options = BoardOptions::optional_resource_or_default(board_options);
 
-        let tile_map = TileMap::new_with_bombs(
+        let seed = board_options.seed_or_random();
+        log::info!("board seed: {}", seed);
+        let mut game = Game::new(TileMap::new_with_bombs_seeded(
             board_options.map_size.0,
             board_options.map_size.1,
             board_options.bomb_count,
-        );
+            seed,
+        ));
         #[cfg(feature = "debug")]
-        log::info!("{}", tile_map.console_output());
+        log::info!("{}", game.tile_map().console_output());
+
+        let mut safe_start_outcomes = Vec::new();
+        if board_options.safe_start {
+            let first_empty = game
+                .tile_map()
+                .map()
+                .iter()
+                .enumerate()
+                .find_map(|(y, row)| {
+                    row.iter()
+                        .position(|tile| *tile == Tile::Empty)
+                        .map(|x| Coordinates {
+                            x: x as u16,
+                            y: y as u16,
+                        })
+                });
+            if let Some(coordinates) = first_empty {
+                safe_start_outcomes = game.reveal(coordinates);
+            }
+        }
+        let tile_map = game.tile_map();
 
         let tile_padding = board_options.tile_padding;
         let tile_size = board_options.compute_tile_size(
@@ -87,16 +117,9 @@ impl<T> BoardPlugin<T> {
             tile_map.height() as f32 * tile_size,
         );
         log::info!("board size: {}", board_size);
-        let board_position = match board_options.position {
-            BoardPositionOption::Centered { offset } => {
-                Vec3::new(-(board_size.x / 2.), -(board_size.y / 2.), 0.) + offset
-            }
-            BoardPositionOption::Custom(p) => p,
-        };
+        let board_position = board_options.compute_board_position(board_size);
 
         // Create entities
-        let mut safe_start_entity = None;
-
         let mut board_entities = None;
 
         let mut background_entity = None;
@@ -133,19 +156,32 @@ impl<T> BoardPlugin<T> {
                     // Spawn tiles
                     board_entities = Some(Self::spawn_tiles(
                         parent,
-                        &tile_map,
+                        tile_map,
                         tile_size,
                         tile_padding,
                         &board_assets,
                         &mut covered_tiles,
-                        &mut safe_start_entity,
                     ));
                 });
             board_entity_commands.id()
         };
 
-        commands.insert_resource(Board {
-            tile_map,
+        for outcome in safe_start_outcomes {
+            if let Outcome::Revealed {
+                coordinates,
+                distance,
+            } = outcome
+            {
+                if let Some(cover) = covered_tiles.remove(&coordinates) {
+                    let delay = board_options.reveal_animation.delay(distance);
+                    commands.entity(cover).insert(Uncover { delay });
+                }
+            }
+        }
+
+        commands.entity(board_entity).insert(board_options.clone());
+        commands.entity(board_entity).insert(Board {
+            game,
             bounds: Bounds2 {
                 position: board_position.xy(),
                 size: board_size,
@@ -156,15 +192,10 @@ impl<T> BoardPlugin<T> {
             entity: board_entity,
             background_entity: background_entity.unwrap(),
             entities: board_entities.unwrap(),
-            marked_tiles: Vec::new(),
             canvas_size: get_canvas_size().unwrap(),
+            seed,
+            counters: default(),
         });
-
-        if board_options.safe_start {
-            if let Some(entity) = safe_start_entity {
-                commands.entity(entity).insert(Uncover);
-            }
-        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -175,9 +206,8 @@ impl<T> BoardPlugin<T> {
         tile_padding: f32,
         board_assets: &BoardAssets,
         covered_tiles: &mut HashMap<Coordinates, Entity>,
-        safe_start_entity: &mut Option<Entity>,
-    ) -> Vec<TileEntities> {
-        let mut tile_entities = Vec::new();
+    ) -> HashMap<Coordinates, TileEntities> {
+        let mut tile_entities = HashMap::new();
 
         // Tiles
         for (y, tile_row) in tile_map.map().iter().enumerate() {
@@ -227,16 +257,12 @@ impl<T> BoardPlugin<T> {
                     let entity = entity_commands.id();
                     cover_id = Some(entity);
                     covered_tiles.insert(coordinates, entity);
-
-                    if safe_start_entity.is_none() && *tile == Tile::Empty {
-                        *safe_start_entity = Some(entity);
-                    }
                 });
 
                 // Create tile-specific components
                 let mut kind_id = None;
                 match tile {
-                    Tile::Bomb => {
+                    Tile::Bomb(_) => {
                         cmd.insert(Bomb);
                         cmd.with_children(|parent| {
                             kind_id = Some(
@@ -273,14 +299,17 @@ impl<T> BoardPlugin<T> {
                         });
                     }
 
-                    Tile::Empty => (),
+                    Tile::Empty | Tile::Void => (),
                 };
 
-                tile_entities.push(TileEntities {
-                    root: root_id,
-                    cover: cover_id.unwrap(),
-                    kind: kind_id,
-                });
+                tile_entities.insert(
+                    coordinates,
+                    TileEntities {
+                        root: root_id,
+                        cover: cover_id.unwrap(),
+                        kind: kind_id,
+                    },
+                );
             }
         }
 
@@ -312,8 +341,9 @@ impl<T> BoardPlugin<T> {
         }
     }
 
-    fn cleanup_board(board: Res<Board>, mut commands: Commands) {
-        commands.entity(board.entity).despawn_recursive();
-        commands.remove_resource::<Board>();
+    fn cleanup_board(boards: Query<Entity, With<Board>>, mut commands: Commands) {
+        for board in boards.iter() {
+            commands.entity(board).despawn_recursive();
+        }
     }
 }
@@ -1,6 +1,10 @@
 use crate::{
     components::*,
-    events::{BoardCompletedEvent, BombExplosionEvent, TileMarkEvent, TileTriggerEvent},
+    events::{
+        BoardCompletedEvent, BoardOutcomeEvent, BombExplosionEvent, GameFinishedEvent,
+        HintRequestEvent, HintResultEvent, TileChordEvent, TileMarkEvent, TileTriggerEvent,
+        UndoRequestEvent,
+    },
     resources::*,
     systems::*,
     util::*,
@@ -19,21 +23,70 @@ fn error_handler(In(result): In<Result<(), QueryEntityError>>) {
 
 impl<T: States> Plugin for BoardPlugin2<T> {
     fn build(&self, app: &mut App) {
+        app.init_resource::<KeyBindings>();
+        app.init_resource::<KeyboardCursor>();
+        app.init_resource::<GamepadCursors>();
+
         app.add_systems(OnEnter(self.running_state.clone()), Self::create_board);
         app.add_systems(OnExit(self.running_state.clone()), Self::cleanup_board);
 
         app.add_systems(
             FixedUpdate,
             (
-                mouse_input,
-                touch_input::<_, TouchInputTouchInputDeps>,
-                read_tile_trigger_event,
-                mark_tiles,
+                (
+                    mouse_input,
+                    touch_input::<_, TouchInputTouchInputDeps>,
+                    hint_input.after(touch_input::<_, TouchInputTouchInputDeps>),
+                    keyboard_cursor_input,
+                    gamepad_input,
+                )
+                    .in_set(BoardInputSet),
+                request_hint.after(hint_input),
+                read_tile_trigger_event.after(request_hint),
+                read_tile_chord_event.after(read_tile_trigger_event),
+                mark_tiles.after(read_tile_chord_event),
+            )
+                .run_if(in_state(self.running_state.clone()))
+                .run_if(in_state(GameState::Playing)),
+        );
+
+        // A lost game can be taken back in practice mode
+        app.add_systems(
+            FixedUpdate,
+            (
+                undo_input.in_set(BoardInputSet),
+                undo_actions.after(mark_tiles),
+            )
+                .run_if(in_state(self.running_state.clone())),
+        );
+
+        app.add_systems(
+            FixedUpdate,
+            (
+                apply_board_outcomes
+                    .after(read_tile_trigger_event)
+                    .after(read_tile_chord_event)
+                    .after(mark_tiles)
+                    .after(undo_actions),
+                explode_board.after(apply_board_outcomes),
+                update_game_state.after(explode_board),
                 uncover_tiles,
             )
                 .run_if(in_state(self.running_state.clone())),
         );
 
+        app.add_systems(
+            Update,
+            summarize_games
+                .after(update_game_timer)
+                .run_if(in_state(self.running_state.clone())),
+        );
+
+        app.add_systems(
+            Update,
+            update_wrap_ghosts.run_if(in_state(self.running_state.clone())),
+        );
+
         app.add_systems(
             Update,
             canvas_size_updater
@@ -42,9 +95,15 @@ impl<T: States> Plugin for BoardPlugin2<T> {
         );
 
         app.add_event::<BoardCompletedEvent>();
+        app.add_event::<BoardOutcomeEvent>();
         app.add_event::<BombExplosionEvent>();
+        app.add_event::<GameFinishedEvent>();
+        app.add_event::<HintRequestEvent>();
+        app.add_event::<HintResultEvent>();
+        app.add_event::<TileChordEvent>();
         app.add_event::<TileMarkEvent>();
         app.add_event::<TileTriggerEvent>();
+        app.add_event::<UndoRequestEvent>();
 
         log::info!("Loaded Board Plugin");
     }
@@ -52,50 +111,150 @@ impl<T: States> Plugin for BoardPlugin2<T> {
 
 struct SpawnTilesReturn {
     board_children: Vec<Entity>,
-    board_entities: Vec<TileEntities>,
+    board_entities: HashMap<Coordinates, TileEntities>,
     covered_tiles: HashMap<Coordinates, Entity>,
-    safe_start_entity: Option<Entity>,
 }
 
 impl<T> BoardPlugin2<T> {
     pub fn create_board(
         mut commands: Commands,
         board_options: Option<Res<BoardOptions>>,
+        pending_resume: Option<Res<PendingResume>>,
         windows: Query<&Window>,
         board_assets: Res<BoardAssets>,
     ) {
         let window = windows.get_single().unwrap();
 
+        // Resume the boards of a loaded game instead of starting a new one
+        if let Some(pending_resume) = pending_resume.filter(|v| v.is_pending()) {
+            for (board_options, game, counters) in pending_resume.boards.iter() {
+                Self::spawn_game_board(
+                    &mut commands,
+                    board_options,
+                    game.clone(),
+                    *counters,
+                    &[],
+                    window,
+                    &board_assets,
+                );
+            }
+            return;
+        }
+
         let board_options = BoardOptions::optional_resource_or_default(board_options);
+        if let Err(error) = board_options.validate() {
+            log::error!("Can not create the board: {}", error);
+            return;
+        }
+
+        Self::spawn_board(&mut commands, &board_options, window, &board_assets);
+    }
 
+    /// Spawns a board played alongside the other boards, returning the board entity. Boards must
+    /// be positioned so that they do not overlap.
+    pub fn spawn_board(
+        commands: &mut Commands,
+        board_options: &BoardOptions,
+        window: &Window,
+        board_assets: &BoardAssets,
+    ) -> Entity {
         // Create the tile map
-        let tile_map = TileMap::new_with_bombs(
-            board_options.map_size.0,
-            board_options.map_size.1,
-            board_options.bomb_count,
-        );
+        let seed = board_options.seed_or_random();
+        log::info!("board seed: {}", seed);
+        let (width, height) = board_options.map_size;
+        let mut game = match board_options.generation {
+            BoardGenerationOption::Immediate => {
+                Game::new_seeded(width, height, board_options.bomb_count, seed)
+            }
+            BoardGenerationOption::FirstTrigger { opening } => {
+                let pending_layout = PendingLayout {
+                    bomb_count: board_options.bomb_count,
+                    opening,
+                    no_guess_max_attempts: None,
+                };
+                Game::with_pending_layout(width, height, pending_layout, seed)
+            }
+            BoardGenerationOption::NoGuess { max_attempts } => {
+                let pending_layout = PendingLayout {
+                    bomb_count: board_options.bomb_count,
+                    opening: true,
+                    no_guess_max_attempts: Some(max_attempts),
+                };
+                Game::with_pending_layout(width, height, pending_layout, seed)
+            }
+        }
+        .with_topology(board_options.topology)
+        .with_wrap(board_options.wrap)
+        .with_neighborhood(board_options.neighborhood.clone())
+        .with_max_tile_bombs(board_options.max_tile_bombs);
+        if let Some(mask) = &board_options.mask {
+            game = game.with_mask(mask.clone());
+        }
         #[cfg(feature = "debug")]
-        log::info!("{}", tile_map.console_output());
+        log::info!("{}", game.tile_map().console_output());
+
+        // A pending layout has no bombs yet, the first trigger is already safe
+        let mut safe_start_outcomes = Vec::new();
+        if board_options.safe_start && game.pending_layout().is_none() {
+            let first_empty = game
+                .tile_map()
+                .map()
+                .iter()
+                .enumerate()
+                .find_map(|(y, row)| {
+                    row.iter()
+                        .position(|tile| *tile == Tile::Empty)
+                        .map(|x| Coordinates {
+                            x: x as u16,
+                            y: y as u16,
+                        })
+                });
+            if let Some(coordinates) = first_empty {
+                safe_start_outcomes = game.reveal(coordinates);
+            }
+        }
+
+        Self::spawn_game_board(
+            commands,
+            board_options,
+            game,
+            default(),
+            &safe_start_outcomes,
+            window,
+            board_assets,
+        )
+    }
+
+    /// Spawns a board showing the state of a game, like `spawn_board`, counting what the player
+    /// already did on it from `counters`. The covers of the tiles revealed by `reveal_outcomes`
+    /// are removed by the reveal animation, the ones of the other revealed tiles right away.
+    pub fn spawn_game_board(
+        commands: &mut Commands,
+        board_options: &BoardOptions,
+        game: Game,
+        counters: BoardCounters,
+        reveal_outcomes: &[Outcome],
+        window: &Window,
+        board_assets: &BoardAssets,
+    ) -> Entity {
+        let tile_map = game.tile_map();
 
         let tile_padding = board_options.tile_padding;
+        let map_size = U16Vec2::new(tile_map.width(), tile_map.height());
         let tile_size = board_options.compute_tile_size(
             &Vec2::new(window.resolution.width(), window.resolution.height()),
-            U16Vec2::new(tile_map.width(), tile_map.height()),
+            map_size,
         );
 
-        let board_size = Vec2::new(
-            tile_map.width() as f32 * tile_size,
-            tile_map.height() as f32 * tile_size,
-        );
+        let board_size = tile_map.topology().size_in_tiles(map_size) * tile_size;
         log::info!("board size: {}", board_size);
-        let board_position = match board_options.position {
-            BoardPositionOption::Centered { offset } => {
-                Vec3::new(-(board_size.x / 2.), -(board_size.y / 2.), 0.) + offset
-            }
-            BoardPositionOption::Custom(p) => p,
-        };
+        let board_position = board_options.compute_board_position(board_size);
 
-        // Spawn background
+        // Spawn background, hidden behind a shaped board where it would show the void cells
+        let visibility = match tile_map.mask() {
+            None => Visibility::Inherited,
+            Some(_) => Visibility::Hidden,
+        };
         let background_entity = commands
             .spawn(SpriteBundle {
                 sprite: Sprite {
@@ -105,19 +264,45 @@ impl<T> BoardPlugin2<T> {
                 },
                 texture: board_assets.board_material.texture.clone(),
                 transform: Transform::from_xyz(board_size.x / 2., board_size.y / 2., 0.),
+                visibility,
                 ..default()
             })
             .insert(Name::new("Background"))
             .id();
 
         // Spawn tiles
-        let spawn_tiles_return = Self::spawn_tiles(
-            &mut commands,
-            &tile_map,
-            tile_size,
-            tile_padding,
-            &board_assets,
-        );
+        let mut spawn_tiles_return =
+            Self::spawn_tiles(commands, tile_map, tile_size, tile_padding, board_assets);
+
+        let reveal_distances: HashMap<Coordinates, u32> = reveal_outcomes
+            .iter()
+            .filter_map(|outcome| match outcome {
+                Outcome::Revealed {
+                    coordinates,
+                    distance,
+                } => Some((*coordinates, *distance)),
+                _ => None,
+            })
+            .collect();
+        let covered_tiles = &mut spawn_tiles_return.covered_tiles;
+        covered_tiles.retain(|coordinates, cover| {
+            if game.is_covered(*coordinates) {
+                return true;
+            }
+
+            let distance = reveal_distances.get(coordinates).copied().unwrap_or(0);
+            let delay = board_options.reveal_animation.delay(distance);
+            commands.entity(*cover).insert(Uncover { delay });
+            false
+        });
+        for coordinates in game.iter_flagged() {
+            if let Some(cover) = covered_tiles.get(&coordinates) {
+                let flag_count = game.flag_count(coordinates);
+                commands.entity(*cover).with_children(|parent| {
+                    spawn_flag(parent, board_assets, tile_size, flag_count);
+                });
+            }
+        }
 
         let board_entity = commands
             .spawn_empty()
@@ -129,9 +314,13 @@ impl<T> BoardPlugin2<T> {
             .push_children(&spawn_tiles_return.board_children)
             .push_children(&[background_entity])
             .id();
+        let wrap_ghosts = spawn_wrap_ghosts(commands, board_entity, tile_map);
+        commands.entity(board_entity).push_children(&wrap_ghosts);
 
-        commands.insert_resource(Board {
-            tile_map,
+        let seed = game.seed();
+        commands.entity(board_entity).insert(board_options.clone());
+        commands.entity(board_entity).insert(Board {
+            game,
             bounds: Bounds2 {
                 position: board_position.xy(),
                 size: board_size,
@@ -142,18 +331,14 @@ impl<T> BoardPlugin2<T> {
             entity: board_entity,
             background_entity,
             entities: spawn_tiles_return.board_entities,
-            marked_tiles: Vec::new(),
             canvas_size: get_canvas_size().unwrap(),
+            seed,
+            counters,
         });
 
-        if board_options.safe_start {
-            if let Some(entity) = spawn_tiles_return.safe_start_entity {
-                commands.entity(entity).insert(Uncover);
-            }
-        }
+        board_entity
     }
 
-    #[allow(clippy::too_many_arguments)]
     fn spawn_tiles(
         commands: &mut Commands,
         tile_map: &TileMap,
@@ -162,14 +347,16 @@ impl<T> BoardPlugin2<T> {
         board_assets: &BoardAssets,
     ) -> SpawnTilesReturn {
         let mut children = Vec::new();
-        let mut tile_entities = Vec::new();
-        let mut covered_tiles =
-            HashMap::with_capacity((tile_map.width() * tile_map.height()).into());
-        let mut safe_start_entity = None;
+        let tile_count = tile_map.grid().tile_count();
+        let mut tile_entities = HashMap::with_capacity(tile_count);
+        let mut covered_tiles = HashMap::with_capacity(tile_count);
 
-        // Tiles
+        // Tiles, the void cells are left empty
         for (y, tile_row) in tile_map.map().iter().enumerate() {
             for (x, tile) in tile_row.iter().enumerate() {
+                if *tile == Tile::Void {
+                    continue;
+                }
                 let coordinates = Coordinates {
                     x: x as u16,
                     y: y as u16,
@@ -186,10 +373,11 @@ impl<T> BoardPlugin2<T> {
                         custom_size: Some(Vec2::splat(tile_size - tile_padding)),
                         ..Default::default()
                     },
-                    transform: Transform::from_xyz(
-                        (x as f32 * tile_size) + (tile_size / 2.),
-                        (y as f32 * tile_size) + (tile_size / 2.),
-                        1.,
+                    transform: Transform::from_translation(
+                        tile_map
+                            .topology()
+                            .tile_translation(coordinates, tile_size)
+                            .extend(1.),
                     ),
                     texture: board_assets.tile_material.texture.clone(),
                     ..Default::default()
@@ -201,75 +389,27 @@ impl<T> BoardPlugin2<T> {
                 let mut cover_id = None;
 
                 cmd.with_children(|parent| {
-                    let mut entity_commands = parent.spawn(SpriteBundle {
-                        sprite: Sprite {
-                            custom_size: Some(Vec2::splat(tile_size - tile_padding)),
-                            color: board_assets.covered_tile_material.color,
-                            ..default()
-                        },
-                        texture: board_assets.covered_tile_material.texture.clone(),
-                        transform: Transform::from_xyz(0.0, 0., 2.),
-                        ..default()
-                    });
+                    let mut entity_commands =
+                        parent.spawn(board_assets.cover_sprite_bundle(tile_size - tile_padding));
                     entity_commands.insert(Name::new("Tile Cover"));
 
                     let entity = entity_commands.id();
                     cover_id = Some(entity);
                     covered_tiles.insert(coordinates, entity);
-
-                    if safe_start_entity.is_none() && *tile == Tile::Empty {
-                        safe_start_entity = Some(entity);
-                    }
                 });
 
                 // Create tile-specific components
-                let mut kind_id = None;
-                match tile {
-                    Tile::Bomb => {
-                        cmd.insert(Bomb);
-                        cmd.with_children(|parent| {
-                            kind_id = Some(
-                                parent
-                                    .spawn(SpriteBundle {
-                                        sprite: Sprite {
-                                            color: board_assets.bomb_material.color,
-                                            custom_size: Some(Vec2::splat(
-                                                tile_size - tile_padding,
-                                            )),
-                                            ..default()
-                                        },
-                                        transform: Transform::from_xyz(0., 0., 1.),
-                                        texture: board_assets.bomb_material.texture.clone(),
-                                        ..default()
-                                    })
-                                    .id(),
-                            );
-                        });
-                    }
-
-                    Tile::BombNeighbor(v) => {
-                        cmd.insert(BombNeighbor { number: *v });
-                        cmd.with_children(|parent| {
-                            kind_id = Some(
-                                parent
-                                    .spawn(Self::bomb_count_text_bundle(
-                                        *v,
-                                        board_assets,
-                                        tile_size - tile_padding,
-                                    ))
-                                    .id(),
-                            );
-                        });
-                    }
-
-                    Tile::Empty => (),
-                };
-
-                tile_entities.push(TileEntities {
-                    root: root_id,
-                    cover: cover_id.unwrap(),
-                    kind: kind_id,
-                });
+                let kind_id =
+                    spawn_tile_kind(&mut cmd, tile, board_assets, tile_size - tile_padding);
+
+                tile_entities.insert(
+                    coordinates,
+                    TileEntities {
+                        root: root_id,
+                        cover: cover_id.unwrap(),
+                        kind: kind_id,
+                    },
+                );
             }
         }
 
@@ -277,37 +417,12 @@ impl<T> BoardPlugin2<T> {
             board_children: children,
             board_entities: tile_entities,
             covered_tiles,
-            safe_start_entity,
         }
     }
 
-    /// Generates the bomb counter text 2D Bundle for a given value
-    fn bomb_count_text_bundle(number: u8, board_assets: &BoardAssets, size: f32) -> Text2dBundle {
-        let text = number.to_string();
-        let color = board_assets.bomb_number_color(number);
-
-        let y_offset = -size / 10.;
-
-        Text2dBundle {
-            text: Text {
-                sections: vec![TextSection {
-                    value: text,
-                    style: TextStyle {
-                        color,
-                        font: board_assets.bomb_number_font.clone(),
-                        font_size: size,
-                    },
-                }],
-                justify: JustifyText::Center,
-                ..default()
-            },
-            transform: Transform::from_xyz(0., y_offset, 1.),
-            ..default()
+    fn cleanup_board(boards: Query<Entity, With<Board>>, mut commands: Commands) {
+        for board in boards.iter() {
+            commands.entity(board).despawn_recursive();
         }
     }
-
-    fn cleanup_board(board: Res<Board>, mut commands: Commands) {
-        commands.entity(board.entity).despawn_recursive();
-        commands.remove_resource::<Board>();
-    }
 }
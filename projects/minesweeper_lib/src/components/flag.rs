@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// Identifies the flags of a tile cover, and the crosses replacing wrong flags
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct Flag;
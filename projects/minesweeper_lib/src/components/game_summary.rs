@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use minesweeper_core::TileMapAnalysis;
+
+/// How the game of a board was played, added to the board once its game is over
+#[derive(Clone, Copy, Debug, Default, PartialEq, Component, Reflect)]
+pub struct GameSummary {
+    pub won: bool,
+
+    /// Seconds spent playing
+    pub time: f32,
+
+    /// How hard the map was to clear
+    pub analysis: TileMapAnalysis,
+
+    /// The tile events the player sent to the board
+    pub clicks: u32,
+
+    /// The clicks which changed the game
+    pub useful_clicks: u32,
+}
+
+impl GameSummary {
+    /// Returns the 3BV of the map per click, 1 when the map was cleared with the fewest clicks
+    pub fn efficiency(&self) -> f32 {
+        match self.clicks {
+            0 => 0.,
+            clicks => self.analysis.three_bv as f32 / clicks as f32,
+        }
+    }
+
+    /// Returns the 3BV of the map cleared per second
+    pub fn three_bv_per_second(&self) -> f32 {
+        if self.time > 0. {
+            self.analysis.three_bv as f32 / self.time
+        } else {
+            0.
+        }
+    }
+}
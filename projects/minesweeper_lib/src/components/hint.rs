@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// Identifies the highlight of a hinted tile
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct Hint;
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+/// Identifies the root node of the HUD
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct Hud;
+
+/// Identifies the HUD text showing the number of unmarked bombs
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct HudMineCounter;
+
+/// Identifies the HUD text showing the elapsed time
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct HudTimer;
+
+/// Identifies the HUD button restarting the game
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct HudResetButton;
+
+/// Identifies the panel summarizing the won games
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct HudWinScreen;
@@ -1,13 +1,26 @@
 mod bomb;
 mod bomb_neighbor;
-mod coordinates;
 mod cover;
+mod flag;
+mod game_summary;
+mod hint;
+mod hud;
+mod stats_recorded;
+mod stats_screen;
 mod touch_interpretation_component;
 mod uncover;
+mod wrap_ghost;
 
 pub use bomb::*;
 pub use bomb_neighbor::*;
-pub use coordinates::*;
 pub use cover::*;
+pub use flag::*;
+pub use game_summary::*;
+pub use hint::*;
+pub use hud::*;
+pub use minesweeper_core::Coordinates;
+pub use stats_recorded::*;
+pub use stats_screen::*;
 pub use touch_interpretation_component::*;
 pub use uncover::*;
+pub use wrap_ghost::*;
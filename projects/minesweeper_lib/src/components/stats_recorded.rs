@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// Identifies boards whose game is already counted in the statistics, or must never be
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct StatsRecorded;
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+/// Identifies the root node of the statistics screen
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct StatsScreen;
+
+/// Identifies the text of the statistics screen listing the categories
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct StatsScreenText;
+
+/// Identifies the statistics screen button resetting the statistics
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
+pub struct StatsResetButton;
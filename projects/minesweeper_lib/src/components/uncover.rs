@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
-/// Identifies uncovered tiles
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Component, Reflect)]
-pub struct Uncover;
+/// Identifies the cover of a revealed tile, despawned once `delay` seconds have passed
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, Component, Reflect)]
+pub struct Uncover {
+    pub delay: f32,
+}
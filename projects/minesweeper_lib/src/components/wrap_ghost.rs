@@ -0,0 +1,15 @@
+use crate::{components::Coordinates, util::TileState};
+use bevy::prelude::*;
+
+/// A faded copy of a tile along the opposite edge of a wrapped board, showing which tiles are
+/// neighbors across the edge
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+pub struct WrapGhost {
+    pub board: Entity,
+    /// The copied tile
+    pub coordinates: Coordinates,
+    /// The number of board periods between the tile and its copy on each axis
+    pub shift: Vec2,
+    /// The state, flag count and size of the tile when its copy was last drawn
+    pub shown: Option<(TileState, u8, f32)>,
+}
@@ -0,0 +1,85 @@
+#[cfg(feature = "wasm")]
+mod internal {
+    use serde::{Deserialize, Serialize};
+    use wasm_bindgen::prelude::*;
+
+    /// The size and bomb count of a game
+    #[wasm_bindgen]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum Difficulty {
+        /// 9x9 tiles and 10 bombs
+        #[default]
+        Beginner,
+        /// 16x16 tiles and 40 bombs
+        Intermediate,
+        /// 30x16 tiles and 99 bombs
+        Expert,
+        /// The tile count and bomb count of the configuration
+        Custom,
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+mod internal {
+    use serde::{Deserialize, Serialize};
+
+    /// The size and bomb count of a game
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum Difficulty {
+        /// 9x9 tiles and 10 bombs
+        #[default]
+        Beginner,
+        /// 16x16 tiles and 40 bombs
+        Intermediate,
+        /// 30x16 tiles and 99 bombs
+        Expert,
+        /// The tile count and bomb count of the configuration
+        Custom,
+    }
+}
+
+pub use internal::*;
+
+impl Difficulty {
+    /// Returns the map size and bomb count of a preset, `None` for a custom game
+    pub fn preset(&self) -> Option<((u16, u16), u16)> {
+        match self {
+            Self::Beginner => Some(((9, 9), 10)),
+            Self::Intermediate => Some(((16, 16), 40)),
+            Self::Expert => Some(((30, 16), 99)),
+            Self::Custom => None,
+        }
+    }
+
+    /// Returns the preset with the map size and bomb count, `Custom` when none has them
+    pub fn of(map_size: (u16, u16), bomb_count: u16) -> Self {
+        [Self::Beginner, Self::Intermediate, Self::Expert]
+            .into_iter()
+            .find(|difficulty| difficulty.preset() == Some((map_size, bomb_count)))
+            .unwrap_or(Self::Custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_have_the_classic_sizes() {
+        assert_eq!(Difficulty::Beginner.preset(), Some(((9, 9), 10)));
+        assert_eq!(Difficulty::Intermediate.preset(), Some(((16, 16), 40)));
+        assert_eq!(Difficulty::Expert.preset(), Some(((30, 16), 99)));
+        assert_eq!(Difficulty::Custom.preset(), None);
+    }
+
+    #[test]
+    fn of_finds_the_preset_of_a_size_and_bomb_count() {
+        assert_eq!(Difficulty::of((9, 9), 10), Difficulty::Beginner);
+        assert_eq!(Difficulty::of((16, 16), 40), Difficulty::Intermediate);
+        assert_eq!(Difficulty::of((30, 16), 99), Difficulty::Expert);
+
+        // A preset size with another bomb count, or the reverse, is custom
+        assert_eq!(Difficulty::of((9, 9), 11), Difficulty::Custom);
+        assert_eq!(Difficulty::of((16, 30), 99), Difficulty::Custom);
+    }
+}
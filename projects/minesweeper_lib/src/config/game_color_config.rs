@@ -28,6 +28,9 @@ mod internal {
         #[wasm_bindgen(skip)]
         pub bomb_color: Option<U8ColorConfig>,
 
+        #[wasm_bindgen(skip)]
+        pub exploded_tile_color: Option<U8ColorConfig>,
+
         #[wasm_bindgen(skip)]
         pub number_colors: Option<Vec<U8ColorConfig>>,
     }
@@ -44,6 +47,7 @@ mod internal {
             highlighted_tile_color: Option<U8ColorConfig>,
             flag_color: Option<U8ColorConfig>,
             bomb_color: Option<U8ColorConfig>,
+            exploded_tile_color: Option<U8ColorConfig>,
             number_colors: Option<Vec<U8ColorConfig>>,
         ) -> Self {
             Self {
@@ -54,6 +58,7 @@ mod internal {
                 highlighted_tile_color,
                 flag_color,
                 bomb_color,
+                exploded_tile_color,
                 number_colors,
             }
         }
@@ -80,6 +85,8 @@ mod internal {
 
         pub bomb_color: Option<U8ColorConfig>,
 
+        pub exploded_tile_color: Option<U8ColorConfig>,
+
         pub number_colors: Option<Vec<U8ColorConfig>>,
     }
 }
@@ -126,6 +133,11 @@ impl From<GameColorConfig> for BoardColors {
                 .map(|c| c.into())
                 .unwrap_or(default.bomb_color),
 
+            exploded_tile_color: value
+                .exploded_tile_color
+                .map(|c| c.into())
+                .unwrap_or(default.exploded_tile_color),
+
             number_colors: value
                 .number_colors
                 .map(|c| c.into_iter().map(|c| c.into()).collect())
@@ -1,12 +1,14 @@
 #[cfg(feature = "wasm")]
 mod internal {
-    use crate::config::{F32ClampConfig, GameColorConfig, U16Vec2Config, Vec2Config};
+    use crate::config::{
+        Difficulty, F32ClampConfig, GameColorConfig, Generation, U16Vec2Config, Vec2Config,
+    };
     use wasm_bindgen::prelude::*;
 
     #[wasm_bindgen]
     #[derive(Debug, Default, Clone)]
     pub struct GameConfig {
-        /// The number of bombs on the map
+        /// The number of bombs on the map of a custom game
         #[wasm_bindgen(skip)]
         pub bomb_count: Option<u16>,
 
@@ -18,11 +20,39 @@ mod internal {
         #[wasm_bindgen(skip)]
         pub color_config: Option<GameColorConfig>,
 
+        /// The size and bomb count of the game, a custom game when the tile count or the bomb
+        /// count is set and an intermediate game otherwise
+        #[wasm_bindgen(skip)]
+        pub difficulty: Option<Difficulty>,
+
+        /// When the bombs are placed, on the first reveal around an opening by default
+        #[wasm_bindgen(skip)]
+        pub generation: Option<Generation>,
+
+        /// ASCII art shaping the board, one line per row, spaces and dots being void cells. The
+        /// map size becomes the size of the art.
+        #[wasm_bindgen(skip)]
+        pub mask: Option<String>,
+
+        /// The asset path of an image shaping the board, every opaque pixel being a tile. The
+        /// map size becomes the size of the image. Ignored when `mask` is set.
+        #[wasm_bindgen(skip)]
+        pub mask_image: Option<String>,
+
+        /// The most layouts tried to find one which can be solved without guessing, 1000 by
+        /// default
+        #[wasm_bindgen(skip)]
+        pub max_generation_attempts: Option<u32>,
+
         /// The resolution of the game
         #[wasm_bindgen(skip)]
         pub resolution: Option<Vec2Config>,
 
-        /// The number of tiles in the map
+        /// The seed used to place bombs
+        #[wasm_bindgen(skip)]
+        pub seed: Option<u64>,
+
+        /// The number of tiles in the map of a custom game
         #[wasm_bindgen(skip)]
         pub tile_count: Option<U16Vec2Config>,
 
@@ -37,12 +67,19 @@ mod internal {
 
     #[wasm_bindgen]
     impl GameConfig {
+        #[allow(clippy::too_many_arguments)]
         #[wasm_bindgen(constructor)]
         pub fn wasm_constructor(
             bomb_count: Option<u16>,
             canvas_id_selector: Option<String>,
             color_config: Option<GameColorConfig>,
+            difficulty: Option<Difficulty>,
+            generation: Option<Generation>,
+            mask: Option<String>,
+            mask_image: Option<String>,
+            max_generation_attempts: Option<u32>,
             resolution: Option<Vec2Config>,
+            seed: Option<u64>,
             tile_count: Option<U16Vec2Config>,
             tile_padding_size: Option<f32>,
             tile_size: Option<F32ClampConfig>,
@@ -51,7 +88,13 @@ mod internal {
                 bomb_count,
                 canvas_id_selector,
                 color_config,
+                difficulty,
+                generation,
+                mask,
+                mask_image,
+                max_generation_attempts,
                 resolution,
+                seed,
                 tile_count,
                 tile_padding_size,
                 tile_size,
@@ -62,11 +105,13 @@ mod internal {
 
 #[cfg(not(feature = "wasm"))]
 mod internal {
-    use crate::config::{F32ClampConfig, GameColorConfig, U16Vec2Config, Vec2Config};
+    use crate::config::{
+        Difficulty, F32ClampConfig, GameColorConfig, Generation, U16Vec2Config, Vec2Config,
+    };
 
     #[derive(Debug, Default, Clone)]
     pub struct GameConfig {
-        /// The number of bombs on the map
+        /// The number of bombs on the map of a custom game
         pub bomb_count: Option<u16>,
 
         /// The id selector of the canvas to render the game in
@@ -75,10 +120,32 @@ mod internal {
         /// The colors used by the game
         pub color_config: Option<GameColorConfig>,
 
+        /// The size and bomb count of the game, a custom game when the tile count or the bomb
+        /// count is set and an intermediate game otherwise
+        pub difficulty: Option<Difficulty>,
+
+        /// When the bombs are placed, on the first reveal around an opening by default
+        pub generation: Option<Generation>,
+
+        /// ASCII art shaping the board, one line per row, spaces and dots being void cells. The
+        /// map size becomes the size of the art.
+        pub mask: Option<String>,
+
+        /// The asset path of an image shaping the board, every opaque pixel being a tile. The
+        /// map size becomes the size of the image. Ignored when `mask` is set.
+        pub mask_image: Option<String>,
+
+        /// The most layouts tried to find one which can be solved without guessing, 1000 by
+        /// default
+        pub max_generation_attempts: Option<u32>,
+
         /// The resolution of the game
         pub resolution: Option<Vec2Config>,
 
-        /// The number of tiles in the map
+        /// The seed used to place bombs
+        pub seed: Option<u64>,
+
+        /// The number of tiles in the map of a custom game
         pub tile_count: Option<U16Vec2Config>,
 
         /// The size of the padding between tiles
@@ -0,0 +1,57 @@
+#[cfg(feature = "wasm")]
+mod internal {
+    use serde::{Deserialize, Serialize};
+    use wasm_bindgen::prelude::*;
+
+    /// When the bombs of a game are placed
+    #[wasm_bindgen]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum Generation {
+        /// When the board is created
+        Immediate,
+        /// On the first reveal, which is never a bomb
+        FirstTrigger,
+        /// On the first reveal, which always opens an area
+        #[default]
+        Opening,
+        /// On the first reveal, around an opening, so that the board can be solved without
+        /// guessing
+        NoGuess,
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+mod internal {
+    use serde::{Deserialize, Serialize};
+
+    /// When the bombs of a game are placed
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum Generation {
+        /// When the board is created
+        Immediate,
+        /// On the first reveal, which is never a bomb
+        FirstTrigger,
+        /// On the first reveal, which always opens an area
+        #[default]
+        Opening,
+        /// On the first reveal, around an opening, so that the board can be solved without
+        /// guessing
+        NoGuess,
+    }
+}
+
+pub use internal::*;
+
+use crate::resources::BoardGenerationOption;
+
+impl Generation {
+    /// Returns the board generation option, trying at most `max_attempts` layouts for `NoGuess`
+    pub fn option(&self, max_attempts: u32) -> BoardGenerationOption {
+        match self {
+            Self::Immediate => BoardGenerationOption::Immediate,
+            Self::FirstTrigger => BoardGenerationOption::FirstTrigger { opening: false },
+            Self::Opening => BoardGenerationOption::FirstTrigger { opening: true },
+            Self::NoGuess => BoardGenerationOption::NoGuess { max_attempts },
+        }
+    }
+}
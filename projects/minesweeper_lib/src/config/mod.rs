@@ -1,13 +1,17 @@
+mod difficulty;
 mod f32_clamp_config;
 mod game_color_config;
 mod game_config;
+mod generation;
 mod u16_vec2_config;
 mod u8_color_config;
 mod vec2_config;
 
+pub use difficulty::*;
 pub use f32_clamp_config::*;
 pub use game_color_config::*;
 pub use game_config::*;
+pub use generation::*;
 pub use u16_vec2_config::*;
 pub use u8_color_config::*;
 pub use vec2_config::*;
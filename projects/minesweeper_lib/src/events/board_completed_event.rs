@@ -1,4 +1,7 @@
 use bevy::prelude::*;
 
+/// Every safe tile of the board was revealed
 #[derive(Copy, Clone, Debug, Event)]
-pub struct BoardCompletedEvent;
+pub struct BoardCompletedEvent {
+    pub board: Entity,
+}
@@ -0,0 +1,10 @@
+use crate::util::*;
+use bevy::prelude::*;
+
+/// A state change of the game played on a board, sent in the order the changes happened so that
+/// the board can be rendered accordingly
+#[derive(Clone, Copy, Debug, Event)]
+pub struct BoardOutcomeEvent {
+    pub board: Entity,
+    pub outcome: Outcome,
+}
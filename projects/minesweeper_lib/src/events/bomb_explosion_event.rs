@@ -1,4 +1,9 @@
+use crate::components::*;
 use bevy::prelude::*;
 
+/// The bomb at the coordinates of the board was uncovered
 #[derive(Copy, Clone, Debug, Event)]
-pub struct BombExplosionEvent;
+pub struct BombExplosionEvent {
+    pub board: Entity,
+    pub coordinates: Coordinates,
+}
@@ -0,0 +1,9 @@
+use crate::components::GameSummary;
+use bevy::prelude::*;
+
+/// The game of the board is over, won or lost
+#[derive(Copy, Clone, Debug, Event)]
+pub struct GameFinishedEvent {
+    pub board: Entity,
+    pub summary: GameSummary,
+}
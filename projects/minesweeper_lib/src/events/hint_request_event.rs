@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+/// Requests a provably safe tile of the board to be highlighted, and uncovered when `uncover` is
+/// set
+#[derive(Clone, Copy, Debug, Event)]
+pub struct HintRequestEvent {
+    pub board: Entity,
+    pub uncover: bool,
+}
@@ -0,0 +1,10 @@
+use crate::components::*;
+use bevy::prelude::*;
+
+/// The answer to a `HintRequestEvent`, `coordinates` is `None` when no tile is provably safe and
+/// the player must guess
+#[derive(Clone, Copy, Debug, Event)]
+pub struct HintResultEvent {
+    pub board: Entity,
+    pub coordinates: Option<Coordinates>,
+}
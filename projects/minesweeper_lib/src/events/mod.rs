@@ -1,9 +1,21 @@
 mod board_completed_event;
+mod board_outcome_event;
 mod bomb_explosion_event;
+mod game_finished_event;
+mod hint_request_event;
+mod hint_result_event;
+mod tile_chord_event;
 mod tile_mark_event;
 mod tile_trigger_event;
+mod undo_request_event;
 
 pub use board_completed_event::*;
+pub use board_outcome_event::*;
 pub use bomb_explosion_event::*;
+pub use game_finished_event::*;
+pub use hint_request_event::*;
+pub use hint_result_event::*;
+pub use tile_chord_event::*;
 pub use tile_mark_event::*;
 pub use tile_trigger_event::*;
+pub use undo_request_event::*;
@@ -0,0 +1,8 @@
+use crate::components::*;
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug, Event)]
+pub struct TileChordEvent {
+    pub board: Entity,
+    pub coordinates: Coordinates,
+}
@@ -2,4 +2,7 @@ use crate::components::*;
 use bevy::prelude::*;
 
 #[derive(Copy, Clone, Debug, Event)]
-pub struct TileMarkEvent(pub Coordinates);
+pub struct TileMarkEvent {
+    pub board: Entity,
+    pub coordinates: Coordinates,
+}
@@ -2,4 +2,7 @@ use crate::components::*;
 use bevy::prelude::*;
 
 #[derive(Clone, Copy, Debug, Event)]
-pub struct TileTriggerEvent(pub Coordinates);
+pub struct TileTriggerEvent {
+    pub board: Entity,
+    pub coordinates: Coordinates,
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+/// Requests the last action on the board to be undone, or the last undone one to be applied again
+/// when `redo` is set
+#[derive(Clone, Copy, Debug, Event)]
+pub struct UndoRequestEvent {
+    pub board: Entity,
+    pub redo: bool,
+}
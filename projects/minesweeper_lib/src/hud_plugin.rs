@@ -0,0 +1,201 @@
+use crate::{components::*, resources::*, systems::*, util::*};
+use bevy::{log, prelude::*, state::state::FreelyMutableState};
+
+/// Shows the remaining mines, the elapsed time and a button restarting the game above the board,
+/// and the summary of the won games over it. `BoardOptions::hud_height` must reserve the space the
+/// HUD is drawn in.
+pub struct HudPlugin<T> {
+    pub running_state: T,
+    /// The state the game goes through when restarting
+    pub out_state: T,
+}
+
+impl<T: FreelyMutableState> Plugin for HudPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameTimer>();
+        app.init_resource::<PendingRestart>();
+
+        app.add_systems(OnEnter(self.running_state.clone()), Self::create_hud);
+        app.add_systems(OnExit(self.running_state.clone()), Self::cleanup_hud);
+        app.add_systems(OnEnter(GameState::Won), Self::create_win_screen);
+        app.add_systems(OnExit(GameState::Won), Self::cleanup_win_screen);
+
+        app.add_systems(
+            Update,
+            (
+                update_game_timer,
+                update_hud,
+                reset_button_input,
+                gamepad_reset_input,
+                Self::leave(self.out_state.clone()),
+            )
+                .chain()
+                .run_if(in_state(self.running_state.clone())),
+        );
+
+        app.add_systems(
+            Update,
+            Self::restart(self.running_state.clone()).run_if(in_state(self.out_state.clone())),
+        );
+
+        log::info!("Loaded HUD Plugin");
+    }
+}
+
+impl<T: FreelyMutableState> HudPlugin<T> {
+    pub fn create_hud(
+        mut commands: Commands,
+        mut game_timer: ResMut<GameTimer>,
+        board_options: Option<Res<BoardOptions>>,
+        board_assets: Res<BoardAssets>,
+    ) {
+        *game_timer = default();
+
+        let board_options = BoardOptions::optional_resource_or_default(board_options);
+        let colors = &board_options.colors;
+        let height = board_options.hud_height;
+
+        let text_style = |color: Color| TextStyle {
+            font: board_assets.bomb_number_font.clone(),
+            font_size: height / 2.,
+            color,
+        };
+
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Px(height),
+                    padding: UiRect::horizontal(Val::Px(height / 4.)),
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: colors.padding_color.into(),
+                ..default()
+            })
+            .insert(Name::new("HUD"))
+            .insert(Hud)
+            .with_children(|parent| {
+                parent
+                    .spawn(TextBundle::from_section(
+                        "000",
+                        text_style(colors.bomb_color),
+                    ))
+                    .insert(Name::new("Mine Counter"))
+                    .insert(HudMineCounter);
+
+                parent
+                    .spawn(ButtonBundle {
+                        style: Style {
+                            width: Val::Px(height * 0.8),
+                            height: Val::Px(height * 0.8),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        background_color: colors.unknown_tile_color.into(),
+                        ..default()
+                    })
+                    .insert(Name::new("Reset Button"))
+                    .insert(HudResetButton)
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            ":)",
+                            text_style(board_assets.bomb_number_color(1)),
+                        ));
+                    });
+
+                parent
+                    .spawn(TextBundle::from_section(
+                        "000",
+                        text_style(colors.flag_color),
+                    ))
+                    .insert(Name::new("Timer"))
+                    .insert(HudTimer);
+            });
+    }
+
+    /// Shows the 3BV of the won boards and how efficiently they were cleared
+    fn create_win_screen(
+        mut commands: Commands,
+        summaries: Query<&GameSummary>,
+        board_options: Option<Res<BoardOptions>>,
+        board_assets: Res<BoardAssets>,
+    ) {
+        let board_options = BoardOptions::optional_resource_or_default(board_options);
+        let colors = &board_options.colors;
+        let lines: Vec<String> = summaries
+            .iter()
+            .filter(|summary| summary.won)
+            .map(|summary| {
+                format!(
+                    "Won in {:.1}s\n3BV {} ({} openings, {} islands)\n{} clicks, {} useful\nEfficiency {:.0}%, 3BV/s {:.2}",
+                    summary.time,
+                    summary.analysis.three_bv,
+                    summary.analysis.openings,
+                    summary.analysis.islands,
+                    summary.clicks,
+                    summary.useful_clicks,
+                    summary.efficiency() * 100.,
+                    summary.three_bv_per_second()
+                )
+            })
+            .collect();
+
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(board_options.hud_height),
+                    left: Val::Px(0.),
+                    padding: UiRect::all(Val::Px(10.)),
+                    ..default()
+                },
+                background_color: colors.padding_color.into(),
+                ..default()
+            })
+            .insert(Name::new("Win Screen"))
+            .insert(HudWinScreen)
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    lines.join("\n\n"),
+                    TextStyle {
+                        font: board_assets.bomb_number_font.clone(),
+                        font_size: 16.,
+                        color: colors.flag_color,
+                    },
+                ));
+            });
+    }
+
+    fn cleanup_win_screen(mut commands: Commands, win_screens: Query<Entity, With<HudWinScreen>>) {
+        for win_screen in win_screens.iter() {
+            commands.entity(win_screen).despawn_recursive();
+        }
+    }
+
+    fn cleanup_hud(mut commands: Commands, huds: Query<Entity, With<Hud>>) {
+        for hud in huds.iter() {
+            commands.entity(hud).despawn_recursive();
+        }
+    }
+
+    /// Leaves the game once a restart was requested, `restart` enters it again
+    fn leave(out_state: T) -> impl FnMut(Res<PendingRestart>, ResMut<NextState<T>>) {
+        move |pending_restart, mut next_state| {
+            if pending_restart.requested {
+                next_state.set(out_state.clone());
+            }
+        }
+    }
+
+    fn restart(running_state: T) -> impl FnMut(ResMut<PendingRestart>, ResMut<NextState<T>>) {
+        move |mut pending_restart, mut next_state| {
+            if pending_restart.requested {
+                pending_restart.requested = false;
+                next_state.set(running_state.clone());
+            }
+        }
+    }
+}
@@ -8,10 +8,22 @@ pub mod util;
 
 mod board_plugin;
 mod board_plugin_2;
+mod hud_plugin;
+mod probability_overlay_plugin;
+mod replay_plugin;
 mod run;
+mod save_plugin;
+mod stats_plugin;
 mod type_registry;
 
 pub use board_plugin::*;
 pub use board_plugin_2::*;
+pub use hud_plugin::*;
+pub use probability_overlay_plugin::*;
+pub use replay_plugin::*;
 pub use run::*;
+pub use save_plugin::*;
+pub use stats_plugin::*;
 pub use type_registry::*;
+
+pub use minesweeper_core::solver;
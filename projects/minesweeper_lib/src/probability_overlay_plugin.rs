@@ -0,0 +1,32 @@
+use crate::{resources::*, systems::*};
+use bevy::{log, prelude::*};
+
+/// Tints every covered tile by its probability of holding a mine, toggled with `p`
+pub struct ProbabilityOverlayPlugin<T> {
+    pub running_state: T,
+}
+
+impl<T: States> Plugin for ProbabilityOverlayPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProbabilityOverlay>();
+
+        app.add_systems(OnExit(self.running_state.clone()), Self::cleanup_overlay);
+
+        app.add_systems(
+            Update,
+            (toggle_probability_overlay, update_probability_overlay)
+                .chain()
+                .run_if(in_state(self.running_state.clone())),
+        );
+
+        log::info!("Loaded Probability Overlay Plugin");
+    }
+}
+
+impl<T> ProbabilityOverlayPlugin<T> {
+    /// The tinted covers are despawned with the boards, the next boards start untinted
+    fn cleanup_overlay(mut overlay: ResMut<ProbabilityOverlay>) {
+        overlay.tinted_covered_tile_counts.clear();
+        overlay.tinted_marked_tiles.clear();
+    }
+}
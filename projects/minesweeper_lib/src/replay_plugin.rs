@@ -0,0 +1,96 @@
+use crate::{resources::*, systems::*, util::*, BoardPlugin2};
+use bevy::{log, prelude::*};
+
+/// Records the inputs made on the boards, saving the replay once the game is over, and plays the
+/// last replay back with `v`. Replays are verified before being played back and go to the save
+/// storage next to the saved game.
+pub struct ReplayPlugin<T> {
+    pub running_state: T,
+}
+
+impl<T: States> Plugin for ReplayPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayRecorder>();
+        app.init_resource::<ReplayPlayback>();
+
+        app.add_systems(OnExit(self.running_state.clone()), Self::cleanup_replays);
+        app.add_systems(OnEnter(GameState::Won), save_replays);
+        app.add_systems(OnEnter(GameState::Lost), save_replays);
+
+        // The player does not play while a replay is played back
+        app.configure_sets(FixedUpdate, BoardInputSet.run_if(Self::is_not_playing_back));
+
+        app.add_systems(
+            FixedUpdate,
+            (record_replay.run_if(Self::is_not_playing_back), play_replay)
+                .after(BoardInputSet)
+                .after(request_hint)
+                .before(read_tile_trigger_event)
+                .run_if(in_state(self.running_state.clone())),
+        );
+
+        app.add_systems(
+            Update,
+            (replay_input, Self::seek_replay)
+                .chain()
+                .run_if(in_state(self.running_state.clone())),
+        );
+
+        log::info!("Loaded Replay Plugin");
+    }
+}
+
+impl<T: States> ReplayPlugin<T> {
+    fn is_not_playing_back(playback: Res<ReplayPlayback>) -> bool {
+        !playback.is_active()
+    }
+
+    /// Spawns the boards of the playback again at the playback position
+    fn seek_replay(
+        mut commands: Commands,
+        mut playback: ResMut<ReplayPlayback>,
+        boards: Query<Entity, With<Board>>,
+        windows: Query<&Window>,
+        board_assets: Res<BoardAssets>,
+        mut next_game_state: ResMut<NextState<GameState>>,
+    ) {
+        if !playback.seek_requested {
+            return;
+        }
+        playback.seek_requested = false;
+
+        for board in boards.iter() {
+            commands.entity(board).despawn_recursive();
+        }
+
+        let window = windows.get_single().unwrap();
+        let position = playback.position;
+        log::info!("Playing back from {:.1}s", position);
+        for (board, saved_replay) in playback.boards.iter_mut() {
+            let game = match saved_replay.replay.game_at(position) {
+                Ok(game) => game,
+                Err(error) => {
+                    log::error!("Could not play back a board: {}", error);
+                    continue;
+                }
+            };
+            *board = BoardPlugin2::<T>::spawn_game_board(
+                &mut commands,
+                &saved_replay.options,
+                game,
+                default(),
+                &[],
+                window,
+                &board_assets,
+            );
+        }
+
+        next_game_state.set(GameState::Playing);
+    }
+
+    /// The boards are despawned when leaving the game, the next game starts recording again
+    fn cleanup_replays(mut recorder: ResMut<ReplayRecorder>, mut playback: ResMut<ReplayPlayback>) {
+        recorder.recordings.clear();
+        *playback = default();
+    }
+}
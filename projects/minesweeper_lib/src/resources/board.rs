@@ -1,19 +1,24 @@
-use crate::{components::*, resources::*, util::*};
-use bevy::{log, prelude::*, utils::HashMap};
+use crate::{components::*, solver::VisibleBoard, util::*};
+use bevy::{math::U16Vec2, prelude::*, utils::HashMap};
 
-#[derive(Debug, Reflect, Resource)]
-#[reflect(Resource)]
+/// A board: the game played on it and the entities rendering it. Must be used as a component of
+/// the board root entity, several boards can be played at once.
+#[derive(Debug, Reflect, Component)]
+#[reflect(Component)]
 pub struct Board {
-    pub tile_map: TileMap,
+    pub game: Game,
     pub bounds: Bounds2,
     pub tile_size: f32,
     pub tile_padding: f32,
+    /// The cover entities of the tiles which are not revealed
     pub covered_tiles: HashMap<Coordinates, Entity>,
     pub entity: Entity,
     pub background_entity: Entity,
-    pub entities: Vec<TileEntities>,
-    pub marked_tiles: Vec<Coordinates>,
+    /// The entities of every tile, void cells have none
+    pub entities: HashMap<Coordinates, TileEntities>,
     pub canvas_size: Vec2,
+    pub seed: u64,
+    pub counters: BoardCounters,
 }
 
 impl Board {
@@ -28,74 +33,47 @@ impl Board {
             return None;
         }
 
-        // World space to board space
+        // World space to board space, from the bottom left corner
         let coordinates = position - self.bounds.position;
-        Some(Coordinates {
-            x: (coordinates.x / self.tile_size) as u16,
-            y: self.tile_map.height() - ((coordinates.y / self.tile_size) as u16) - 1,
-        })
+        let coordinates = Vec2::new(coordinates.x, self.bounds.size.y - coordinates.y);
+        self.topology()
+            .tile_at(coordinates, self.tile_size, self.map_size())
+            .filter(|coordinates| self.game.tile_map().in_bounds(*coordinates))
     }
 
-    /// Retrieves a covered tile entity
-    pub fn tile_to_uncover(&self, coordinates: &Coordinates) -> Option<&Entity> {
-        if self.marked_tiles.contains(coordinates) {
-            // Return `None` if the tile is marked to prevent marked tiles from being uncovered
-            None
-        } else {
-            self.covered_tiles.get(coordinates)
-        }
+    /// Returns the position of the center of a tile in the board
+    pub fn tile_translation(&self, coordinates: Coordinates) -> Vec2 {
+        self.topology()
+            .tile_translation(coordinates, self.tile_size)
     }
 
-    /// We try to uncover a tile, returning the entity
-    pub fn try_uncover_tile(&mut self, coordinates: &Coordinates) -> Option<Entity> {
-        if self.marked_tiles.contains(coordinates) {
-            self.unmark_tile(coordinates)?;
-        }
-
-        self.covered_tiles.remove(coordinates)
+    pub fn topology(&self) -> Topology {
+        self.game.tile_map().topology()
     }
 
-    /// We retrieve the adjacent covered tile entities of some `coordinates`
-    pub fn adjacent_covered_tiles(&self, coordinates: Coordinates) -> Vec<Entity> {
-        self.tile_map
-            .iter_neighbors(coordinates)
-            .filter_map(|neighbor_coordinates| self.covered_tiles.get(&neighbor_coordinates))
-            .copied()
-            .collect()
+    pub fn map_size(&self) -> U16Vec2 {
+        let tile_map = self.game.tile_map();
+        U16Vec2::new(tile_map.width(), tile_map.height())
     }
 
-    /// Removes the `coords` from `marked_tiles`
-    fn unmark_tile(&mut self, coordinates: &Coordinates) -> Option<Coordinates> {
-        let tile_index = match self
-            .marked_tiles
-            .iter()
-            .position(|current_coordinates| current_coordinates == coordinates)
-        {
-            None => {
-                log::error!("Failed to unmark tile at {}", coordinates);
-                return None;
-            }
-            Some(tile_index) => tile_index,
-        };
-        Some(self.marked_tiles.remove(tile_index))
+    /// Does the board still accept input
+    pub fn is_playing(&self) -> bool {
+        self.game.status() == GameStatus::Playing
     }
 
-    /// Is the board complete
-    pub fn is_completed(&self) -> bool {
-        self.tile_map.bomb_count() as usize == self.covered_tiles.len()
+    /// Removes the cover of a revealed tile from the covered tiles, returning the cover entity
+    pub fn reveal_cover(&mut self, coordinates: &Coordinates) -> Option<Entity> {
+        self.covered_tiles.remove(coordinates)
     }
 
-    /// We try to mark or unmark a tile, returning the entity and if the tile is marked
-    pub fn try_toggle_mark(&mut self, coordinates: &Coordinates) -> Option<(&Entity, bool)> {
-        let mark = if self.marked_tiles.contains(coordinates) {
-            self.unmark_tile(coordinates)?;
-            false
-        } else {
-            self.marked_tiles.push(*coordinates);
-            true
-        };
+    /// Returns the board seen by the player, flags included
+    pub fn visible_board(&self) -> VisibleBoard {
+        VisibleBoard::from_game(&self.game)
+    }
 
-        let covered_tile_entity = self.covered_tiles.get(coordinates)?;
-        Some((covered_tile_entity, mark))
+    /// Returns the board seen by the player, treating flagged tiles as covered so that wrong flags
+    /// can not mislead deductions
+    pub fn visible_board_ignoring_flags(&self) -> VisibleBoard {
+        VisibleBoard::from_game_ignoring_flags(&self.game)
     }
 }
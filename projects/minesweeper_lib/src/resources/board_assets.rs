@@ -15,11 +15,14 @@ pub struct BoardAssets {
     pub bomb_number_font: Handle<Font>,
     pub bomb_number_colors: Vec<Color>,
     pub flag_material: SpriteMaterial,
+    pub wrong_flag_material: SpriteMaterial,
     pub bomb_material: SpriteMaterial,
+    pub exploded_tile_material: SpriteMaterial,
 }
 
 impl BoardAssets {
-    /// Safely retrieves the color matching a bomb counter
+    /// Safely retrieves the color matching a bomb counter, the numbers past the last color use
+    /// the last color
     pub fn bomb_number_color(&self, number: u8) -> Color {
         let counter = number.saturating_sub(1) as usize;
         match self.bomb_number_colors.get(counter) {
@@ -30,4 +33,110 @@ impl BoardAssets {
             },
         }
     }
+
+    /// Generates the sprite 2D Bundle covering a tile for a given size
+    pub fn cover_sprite_bundle(&self, size: f32) -> SpriteBundle {
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::splat(size)),
+                color: self.covered_tile_material.color,
+                ..default()
+            },
+            texture: self.covered_tile_material.texture.clone(),
+            transform: Transform::from_xyz(0., 0., 2.),
+            ..default()
+        }
+    }
+
+    /// Generates the flag sprite 2D Bundle for a given size
+    pub fn flag_sprite_bundle(&self, size: f32) -> SpriteBundle {
+        SpriteBundle {
+            texture: self.flag_material.texture.clone(),
+            sprite: Sprite {
+                custom_size: Some(Vec2::splat(size)),
+                color: self.flag_material.color,
+                ..default()
+            },
+            transform: Transform::from_xyz(0., 0., 1.),
+            ..default()
+        }
+    }
+
+    /// Generates the text 2D Bundle counting the flags or bombs of a tile holding several, in its
+    /// top right corner, for a given tile size
+    pub fn count_badge_text_bundle(&self, count: u8, size: f32) -> Text2dBundle {
+        Text2dBundle {
+            text: Text {
+                sections: vec![TextSection {
+                    value: count.to_string(),
+                    style: TextStyle {
+                        color: self.bomb_number_color(count),
+                        font: self.bomb_number_font.clone(),
+                        font_size: size / 2.,
+                    },
+                }],
+                justify: JustifyText::Center,
+                ..default()
+            },
+            transform: Transform::from_xyz(size / 3., size / 3., 1.),
+            ..default()
+        }
+    }
+
+    /// Generates the two crossed bars marking a wrongly flagged tile for a given size
+    pub fn wrong_flag_sprite_bundles(&self, size: f32) -> [SpriteBundle; 2] {
+        [1., -1.].map(|direction: f32| SpriteBundle {
+            texture: self.wrong_flag_material.texture.clone(),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(size, size / 8.)),
+                color: self.wrong_flag_material.color,
+                ..default()
+            },
+            transform: Transform::from_xyz(0., 0., 1.).with_rotation(Quat::from_rotation_z(
+                direction * std::f32::consts::FRAC_PI_4,
+            )),
+            ..default()
+        })
+    }
+
+    /// Generates the bomb sprite 2D Bundle for a given size
+    pub fn bomb_sprite_bundle(&self, size: f32) -> SpriteBundle {
+        SpriteBundle {
+            sprite: Sprite {
+                color: self.bomb_material.color,
+                custom_size: Some(Vec2::splat(size)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0., 0., 1.),
+            texture: self.bomb_material.texture.clone(),
+            ..default()
+        }
+    }
+
+    /// Generates the bomb counter text 2D Bundle for a given value. Numbers of several digits,
+    /// counted by the larger neighborhoods, are scaled down to fit in the tile.
+    pub fn bomb_count_text_bundle(&self, number: u8, size: f32) -> Text2dBundle {
+        let text = number.to_string();
+        let color = self.bomb_number_color(number);
+
+        let y_offset = -size / 10.;
+        let scale = 1.2 / (text.len() as f32 + 0.2);
+
+        Text2dBundle {
+            text: Text {
+                sections: vec![TextSection {
+                    value: text,
+                    style: TextStyle {
+                        color,
+                        font: self.bomb_number_font.clone(),
+                        font_size: size,
+                    },
+                }],
+                justify: JustifyText::Center,
+                ..default()
+            },
+            transform: Transform::from_xyz(0., y_offset, 1.).with_scale(Vec3::splat(scale)),
+            ..default()
+        }
+    }
 }
@@ -10,6 +10,8 @@ pub struct BoardColors {
     pub highlighted_tile_color: Color,
     pub flag_color: Color,
     pub bomb_color: Color,
+    pub exploded_tile_color: Color,
+    /// The colors of the numbers from 1, the larger numbers use the last one
     pub number_colors: Vec<Color>,
 }
 
@@ -23,6 +25,7 @@ impl Default for BoardColors {
             highlighted_tile_color: Color::srgb_u8(71, 75, 82),
             flag_color: Color::srgb_u8(27, 167, 223),
             bomb_color: Color::srgb_u8(241, 91, 80),
+            exploded_tile_color: Color::srgb_u8(110, 36, 34),
             number_colors: vec![
                 Color::WHITE,
                 Color::srgb_u8(64, 182, 73),  // green
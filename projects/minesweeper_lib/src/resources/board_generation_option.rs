@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Board generation options
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Reflect)]
+pub enum BoardGenerationOption {
+    /// Bombs are placed when the board is created
+    #[default]
+    Immediate,
+
+    /// Bombs are placed when the first tile is triggered so the triggered tile is never a bomb.
+    /// When `opening` is set, the neighbors of the triggered tile are also kept free of bombs so
+    /// the first trigger always reveals an opening.
+    FirstTrigger { opening: bool },
+
+    /// Bombs are placed when the first tile is triggered, around an opening, and the layout is
+    /// regenerated until it can be solved from that opening without guessing. At most
+    /// `max_attempts` layouts are tried before falling back to the one closest to being solved.
+    NoGuess { max_attempts: u32 },
+}
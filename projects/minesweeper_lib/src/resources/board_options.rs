@@ -1,17 +1,38 @@
-use crate::resources::*;
+use crate::{
+    config::Difficulty,
+    resources::*,
+    util::{BoardOptionsError, Grid, Mask, Neighborhood, TileLayout, Topology, Wrap},
+};
 use bevy::{math::U16Vec2, prelude::*};
 use serde::{Deserialize, Serialize};
 
-/// Board generation options which must be used as a resource
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect, Resource)]
-#[reflect(Resource)]
+/// Board generation options which must be used as a resource. Every board also keeps the options
+/// it was spawned with as a component.
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect, Resource, Component)]
+#[reflect(Resource, Component)]
+#[serde(default)]
 pub struct BoardOptions {
     /// Tile map size
     pub map_size: (u16, u16),
 
+    /// How the tiles are laid out
+    pub topology: Topology,
+
+    /// Which edges touch the opposite edge
+    pub wrap: Wrap,
+
+    /// Which tiles are neighbors
+    pub neighborhood: Neighborhood,
+
+    /// Which cells of the map are tiles, every cell when `None`, see `with_mask`
+    pub mask: Option<Mask>,
+
     /// The number of bombs
     pub bomb_count: u16,
 
+    /// The most bombs a tile can hold, the player places as many flags on it
+    pub max_tile_bombs: u8,
+
     /// Board world position
     pub position: BoardPositionOption,
 
@@ -24,35 +45,216 @@ pub struct BoardOptions {
     /// Does the board generate a safe place to start
     pub safe_start: bool,
 
+    /// When the bombs of the board are placed
+    pub generation: BoardGenerationOption,
+
     /// The colors of the board
     pub colors: BoardColors,
+
+    /// The seed used to place bombs, a random seed is picked when `None`
+    pub seed: Option<u64>,
+
+    /// Height reserved at the top of the window for the HUD
+    pub hud_height: f32,
+
+    /// How the covers of revealed tiles are removed
+    pub reveal_animation: RevealAnimationOption,
+
+    /// Can the explosion of a bomb be undone
+    pub practice: bool,
+
+    /// What the tile cursors do at the edges of the board
+    pub cursor_edge: CursorEdgeOption,
 }
 
 impl BoardOptions {
+    /// The largest share of the tiles which can hold a bomb
+    pub const MAX_BOMB_DENSITY: f32 = 0.5;
+
+    /// Sets the map size and bomb count of a difficulty preset, a custom difficulty keeps them
+    pub fn with_difficulty(self, difficulty: Difficulty) -> Self {
+        match difficulty.preset() {
+            Some((map_size, bomb_count)) => Self {
+                map_size,
+                bomb_count,
+                ..self
+            },
+            None => self,
+        }
+    }
+
+    /// Shapes the board like a mask, the map size becomes the size of the mask
+    pub fn with_mask(self, mask: Mask) -> Self {
+        Self {
+            map_size: (mask.width(), mask.height()),
+            mask: Some(mask),
+            ..self
+        }
+    }
+
+    /// Checks that a game can be played with the options: at least one tile is safe, the bombs do
+    /// not exceed the maximum density and leave room for the tiles kept safe for the first trigger
+    pub fn validate(&self) -> Result<(), BoardOptionsError> {
+        let (width, height) = self.map_size;
+        if let Some(mask) = &self.mask {
+            if !mask.fits(width, height) {
+                return Err(BoardOptionsError::InvalidMask);
+            }
+        }
+
+        let tile_count = self.grid().tile_count() as u32;
+        let bomb_count = self.bomb_count;
+        let max_tile_bombs = self.max_tile_bombs as u32;
+        if tile_count == 0 {
+            return Err(BoardOptionsError::EmptyMap);
+        }
+        if max_tile_bombs == 0 {
+            return Err(BoardOptionsError::NoTileBombs);
+        }
+        if bomb_count as u32 > (tile_count - 1) * max_tile_bombs {
+            return Err(BoardOptionsError::TooManyBombs {
+                bomb_count,
+                tile_count,
+            });
+        }
+
+        let max_bomb_count =
+            (tile_count as f32 * max_tile_bombs as f32 * Self::MAX_BOMB_DENSITY) as u32;
+        if bomb_count as u32 > max_bomb_count {
+            return Err(BoardOptionsError::TooDense {
+                bomb_count,
+                max_bomb_count,
+            });
+        }
+
+        if self.topology == Topology::Hex && self.wrap.is_vertical() && height % 2 == 1 {
+            return Err(BoardOptionsError::OddWrappedHexRows { height });
+        }
+
+        self.validate_neighborhood()?;
+
+        if max_tile_bombs > 1 && matches!(self.generation, BoardGenerationOption::NoGuess { .. }) {
+            return Err(BoardOptionsError::NoGuessTileBombs);
+        }
+
+        let start_tile_count = self.start_tile_count();
+        if bomb_count as u32 > tile_count.saturating_sub(start_tile_count) * max_tile_bombs {
+            return Err(BoardOptionsError::NoRoomForStart {
+                bomb_count,
+                start_tile_count,
+                tile_count,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of tiles kept free of bombs for the first trigger: the triggered tile,
+    /// and its neighbors for an opening
+    fn start_tile_count(&self) -> u32 {
+        match self.generation {
+            BoardGenerationOption::Immediate => 0,
+            BoardGenerationOption::FirstTrigger { opening: false } => 1,
+            BoardGenerationOption::FirstTrigger { opening: true }
+            | BoardGenerationOption::NoGuess { .. } => self.grid().neighbor_count() as u32 + 1,
+        }
+    }
+
+    /// Checks that every tile has neighbors at distinct offsets, holding no more bombs than a
+    /// number can count
+    fn validate_neighborhood(&self) -> Result<(), BoardOptionsError> {
+        let square_only = matches!(
+            self.neighborhood,
+            Neighborhood::Cross | Neighborhood::Knight
+        );
+        if square_only && self.topology != Topology::Square {
+            return Err(BoardOptionsError::SquareOnlyNeighborhood);
+        }
+
+        if let Neighborhood::Custom(offsets) = &self.neighborhood {
+            let mut distinct_offsets = offsets.clone();
+            distinct_offsets.sort();
+            distinct_offsets.dedup();
+            if distinct_offsets.len() != offsets.len() || offsets.contains(&(0, 0)) {
+                return Err(BoardOptionsError::InvalidNeighborOffsets);
+            }
+        }
+
+        let neighbor_count = self.grid().neighbor_count();
+        if !(1..=u8::MAX as usize).contains(&neighbor_count) {
+            return Err(BoardOptionsError::NeighborCount { neighbor_count });
+        }
+
+        let max_number = neighbor_count * self.max_tile_bombs as usize;
+        if max_number > u8::MAX as usize {
+            return Err(BoardOptionsError::NumberOverflow { max_number });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the grid of the tile maps of the board
+    pub fn grid(&self) -> Grid {
+        Grid {
+            width: self.map_size.0,
+            height: self.map_size.1,
+            topology: self.topology,
+            wrap: self.wrap,
+            neighborhood: self.neighborhood.clone(),
+            mask: self.mask.clone(),
+        }
+    }
+
     pub fn compute_tile_size(&self, window_size: &Vec2, tile_map_size: U16Vec2) -> f32 {
         match self.tile_size {
             TileSizeOption::Fixed(v) => v,
             TileSizeOption::Adaptive { min, max } => {
-                Self::compute_adaptive_tile_size(window_size, &(min, max), tile_map_size)
+                // The HUD is drawn above the board
+                let available_size = Vec2::new(window_size.x, window_size.y - self.hud_height);
+                // The ghosts of the wrapped edges are drawn around the board
+                let wrapped = Vec2::new(
+                    self.wrap.is_horizontal() as u8 as f32,
+                    self.wrap.is_vertical() as u8 as f32,
+                );
+                let size_in_tiles = self.topology.size_in_tiles(tile_map_size)
+                    + 2. * wrapped * self.topology.tile_step();
+                Self::compute_adaptive_tile_size(&available_size, &(min, max), size_in_tiles)
+            }
+        }
+    }
+
+    /// Computes the world position of the bottom left corner of a board
+    pub fn compute_board_position(&self, board_size: Vec2) -> Vec3 {
+        match self.position {
+            BoardPositionOption::Centered { offset } => {
+                // Center the board in the space left below the HUD
+                Vec3::new(
+                    -(board_size.x / 2.),
+                    -(board_size.y / 2.) - self.hud_height / 2.,
+                    0.,
+                ) + offset
             }
+            BoardPositionOption::Custom(p) => p,
         }
     }
 
-    /// Computes a tile size that matches the window according to the tile map size
+    /// Computes a tile size that matches the window according to the tile map size in tile sizes
     fn compute_adaptive_tile_size(
         window_size: &Vec2,
         (min, max): &(f32, f32),
-        tile_map_size: U16Vec2,
+        size_in_tiles: Vec2,
     ) -> f32 {
-        let width = tile_map_size.x as f32;
-        let height = tile_map_size.y as f32;
-
-        let max_width = window_size.x / width;
-        let max_height = window_size.y / height;
+        let max_width = window_size.x / size_in_tiles.x;
+        let max_height = window_size.y / size_in_tiles.y;
 
         max_width.min(max_height).clamp(*min, *max)
     }
 
+    /// Returns the configured seed or picks a random one
+    pub fn seed_or_random(&self) -> u64 {
+        self.seed.unwrap_or_else(TileMap::random_seed)
+    }
+
     pub fn optional_resource_or_default(v: Option<Res<BoardOptions>>) -> BoardOptions {
         match v {
             None => BoardOptions::default(),
@@ -64,13 +266,216 @@ impl BoardOptions {
 impl Default for BoardOptions {
     fn default() -> Self {
         Self {
-            map_size: (15, 15),
-            bomb_count: 30,
+            map_size: (9, 9),
+            topology: default(),
+            wrap: default(),
+            neighborhood: default(),
+            mask: None,
+            bomb_count: 10,
+            max_tile_bombs: 1,
             position: default(),
             tile_size: default(),
             tile_padding: 0.,
             safe_start: false,
+            generation: default(),
             colors: default(),
+            seed: None,
+            hud_height: 0.,
+            reveal_animation: default(),
+            practice: false,
+            cursor_edge: default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(map_size: (u16, u16), bomb_count: u16) -> BoardOptions {
+        BoardOptions {
+            map_size,
+            bomb_count,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn presets_are_valid() {
+        for (difficulty, map_size, bomb_count) in [
+            (Difficulty::Beginner, (9, 9), 10),
+            (Difficulty::Intermediate, (16, 16), 40),
+            (Difficulty::Expert, (30, 16), 99),
+        ] {
+            let options = BoardOptions::default().with_difficulty(difficulty);
+            assert_eq!(
+                (options.map_size, options.bomb_count),
+                (map_size, bomb_count)
+            );
+            assert_eq!(options.validate(), Ok(()));
+
+            let options = BoardOptions {
+                generation: BoardGenerationOption::NoGuess { max_attempts: 10 },
+                ..options
+            };
+            assert_eq!(options.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn configured_seed_is_used() {
+        let options = BoardOptions {
+            seed: Some(42),
+            ..default()
+        };
+        assert_eq!(options.seed_or_random(), 42);
+    }
+
+    #[test]
+    fn empty_maps_are_rejected() {
+        assert_eq!(
+            options((0, 9), 0).validate(),
+            Err(BoardOptionsError::EmptyMap)
+        );
+
+        let options = options((3, 1), 0).with_mask(Mask::from_ascii("..."));
+        assert_eq!(options.validate(), Err(BoardOptionsError::EmptyMap));
+    }
+
+    #[test]
+    fn masks_of_another_size_are_rejected() {
+        let options = BoardOptions {
+            mask: Some(Mask::from_ascii("##\n##")),
+            ..options((3, 3), 1)
+        };
+        assert_eq!(options.validate(), Err(BoardOptionsError::InvalidMask));
+    }
+
+    #[test]
+    fn bombs_must_leave_a_safe_tile() {
+        assert_eq!(
+            options((3, 3), 9).validate(),
+            Err(BoardOptionsError::TooManyBombs {
+                bomb_count: 9,
+                tile_count: 9
+            })
+        );
+    }
+
+    #[test]
+    fn bombs_must_not_exceed_the_density() {
+        assert_eq!(options((10, 10), 50).validate(), Ok(()));
+        assert_eq!(
+            options((10, 10), 51).validate(),
+            Err(BoardOptionsError::TooDense {
+                bomb_count: 51,
+                max_bomb_count: 50
+            })
+        );
+    }
+
+    #[test]
+    fn bombs_must_leave_room_for_the_start() {
+        let options = |opening| BoardOptions {
+            generation: BoardGenerationOption::FirstTrigger { opening },
+            ..options((3, 3), 4)
+        };
+        assert_eq!(options(false).validate(), Ok(()));
+        assert_eq!(
+            options(true).validate(),
+            Err(BoardOptionsError::NoRoomForStart {
+                bomb_count: 4,
+                start_tile_count: 9,
+                tile_count: 9
+            })
+        );
+    }
+
+    #[test]
+    fn tiles_must_hold_a_bomb() {
+        let options = BoardOptions {
+            max_tile_bombs: 0,
+            ..default()
+        };
+        assert_eq!(options.validate(), Err(BoardOptionsError::NoTileBombs));
+    }
+
+    #[test]
+    fn wrapped_hex_maps_need_an_even_number_of_rows() {
+        let options = |height| BoardOptions {
+            topology: Topology::Hex,
+            wrap: Wrap::Vertical,
+            ..options((10, height), 10)
+        };
+        assert_eq!(options(10).validate(), Ok(()));
+        assert_eq!(
+            options(9).validate(),
+            Err(BoardOptionsError::OddWrappedHexRows { height: 9 })
+        );
+    }
+
+    #[test]
+    fn cross_and_knight_neighborhoods_need_square_tiles() {
+        for neighborhood in [Neighborhood::Cross, Neighborhood::Knight] {
+            let options = BoardOptions {
+                neighborhood,
+                ..default()
+            };
+            assert_eq!(options.validate(), Ok(()));
+
+            let options = BoardOptions {
+                topology: Topology::Hex,
+                ..options
+            };
+            assert_eq!(
+                options.validate(),
+                Err(BoardOptionsError::SquareOnlyNeighborhood)
+            );
         }
     }
+
+    #[test]
+    fn custom_offsets_must_be_distinct_neighbors() {
+        let options = |offsets| BoardOptions {
+            neighborhood: Neighborhood::Custom(offsets),
+            ..default()
+        };
+        assert_eq!(options(vec![(0, 1), (2, 0)]).validate(), Ok(()));
+        assert_eq!(
+            options(vec![(0, 1), (0, 1)]).validate(),
+            Err(BoardOptionsError::InvalidNeighborOffsets)
+        );
+        assert_eq!(
+            options(vec![(0, 0), (0, 1)]).validate(),
+            Err(BoardOptionsError::InvalidNeighborOffsets)
+        );
+        assert_eq!(
+            options(vec![]).validate(),
+            Err(BoardOptionsError::NeighborCount { neighbor_count: 0 })
+        );
+    }
+
+    #[test]
+    fn numbers_must_count_every_bomb_around_a_tile() {
+        // 8 neighbors holding 31 bombs each stay under 255
+        let options = |max_tile_bombs| BoardOptions {
+            max_tile_bombs,
+            ..default()
+        };
+        assert_eq!(options(31).validate(), Ok(()));
+        assert_eq!(
+            options(32).validate(),
+            Err(BoardOptionsError::NumberOverflow { max_number: 256 })
+        );
+    }
+
+    #[test]
+    fn no_guess_layouts_need_a_single_bomb_per_tile() {
+        let options = BoardOptions {
+            max_tile_bombs: 2,
+            generation: BoardGenerationOption::NoGuess { max_attempts: 10 },
+            ..default()
+        };
+        assert_eq!(options.validate(), Err(BoardOptionsError::NoGuessTileBombs));
+    }
 }
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// What a tile cursor does at the edges of the board
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, Reflect)]
+pub enum CursorEdgeOption {
+    /// The cursor stops at the edges
+    #[default]
+    Clamp,
+
+    /// The cursor leaving an edge comes back from the opposite one
+    Wrap,
+}
+
+impl CursorEdgeOption {
+    /// Moves a position on an axis of `size` tiles by `offset` tiles
+    pub fn apply(&self, position: u16, offset: i32, size: u16) -> u16 {
+        let position = position as i32 + offset;
+        match self {
+            Self::Clamp => position.clamp(0, size as i32 - 1) as u16,
+            Self::Wrap => position.rem_euclid(size as i32) as u16,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::util::*;
+use bevy::{log, prelude::*};
+use serde::{Deserialize, Serialize};
+
+/// The statistics of the finished games, kept across sessions. Must be used as a resource.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Resource)]
+pub struct GameStats {
+    /// The statistics of every category a game was played in, in the order they were first played
+    pub categories: Vec<(StatsCategory, CategoryStats)>,
+}
+
+impl GameStats {
+    /// Reads the saved statistics, starting from empty ones when there are none or they can not
+    /// be read
+    pub fn load() -> Self {
+        match read_save(SAVED_STATS_NAME).and_then(|ron| SavedStats::from_ron(&ron)) {
+            Ok(saved_stats) => saved_stats.stats,
+            Err(SaveError::NotFound) => default(),
+            Err(error) => {
+                log::error!("Could not load the statistics: {}", error);
+                default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        match SavedStats::new(self.clone())
+            .to_ron()
+            .and_then(|ron| write_save(SAVED_STATS_NAME, &ron))
+        {
+            Ok(()) => log::info!("Statistics saved"),
+            Err(error) => log::error!("Could not save the statistics: {}", error),
+        }
+    }
+
+    /// Returns the statistics of a category, adding empty ones when it was never played
+    pub fn category_mut(&mut self, category: StatsCategory) -> &mut CategoryStats {
+        let index = match self.categories.iter().position(|(v, _)| *v == category) {
+            Some(index) => index,
+            None => {
+                self.categories.push((category, default()));
+                self.categories.len() - 1
+            }
+        };
+
+        &mut self.categories[index].1
+    }
+}
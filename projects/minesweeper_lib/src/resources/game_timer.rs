@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+/// Measures the time spent playing a board. Must be used as a resource.
+#[derive(Debug, Clone, Default, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct GameTimer {
+    /// Seconds elapsed since the first tile was revealed
+    pub elapsed: f32,
+
+    /// Is the timer counting
+    pub running: bool,
+}
@@ -0,0 +1,9 @@
+use crate::util::*;
+use bevy::{prelude::*, utils::HashMap};
+
+/// The tile cursors of the gamepads, shown after the first use of each gamepad. Must be used as a
+/// resource.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct GamepadCursors {
+    pub cursors: HashMap<Gamepad, GamepadCursor>,
+}
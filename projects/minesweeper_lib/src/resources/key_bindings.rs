@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+/// The keys playing with the keyboard cursor, matched by their position on the keyboard. Must be
+/// used as a resource.
+///
+/// Letter keys only act while the cursor is shown, which the other keys do. The letters also used
+/// by shortcuts, like `s` to save, are then taken by the cursor.
+#[derive(Debug, Clone, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct KeyBindings {
+    pub up: Vec<KeyCode>,
+    pub down: Vec<KeyCode>,
+    pub left: Vec<KeyCode>,
+    pub right: Vec<KeyCode>,
+
+    /// Reveals the tile under the cursor, or chords it when it is a revealed number
+    pub reveal: Vec<KeyCode>,
+    pub flag: Vec<KeyCode>,
+    pub chord: Vec<KeyCode>,
+}
+
+impl KeyBindings {
+    /// Returns the action bound to a key
+    pub fn action(&self, key_code: KeyCode) -> Option<KeyAction> {
+        [
+            (&self.up, KeyAction::Move(0, 1)),
+            (&self.down, KeyAction::Move(0, -1)),
+            (&self.left, KeyAction::Move(-1, 0)),
+            (&self.right, KeyAction::Move(1, 0)),
+            (&self.reveal, KeyAction::Reveal),
+            (&self.flag, KeyAction::Flag),
+            (&self.chord, KeyAction::Chord),
+        ]
+        .into_iter()
+        .find(|(key_codes, _)| key_codes.contains(&key_code))
+        .map(|(_, action)| action)
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            up: vec![KeyCode::ArrowUp, KeyCode::KeyW, KeyCode::KeyK],
+            down: vec![KeyCode::ArrowDown, KeyCode::KeyS, KeyCode::KeyJ],
+            left: vec![KeyCode::ArrowLeft, KeyCode::KeyA, KeyCode::KeyH],
+            // D chords, so the arrow and l move right
+            right: vec![KeyCode::ArrowRight, KeyCode::KeyL],
+            reveal: vec![KeyCode::Space, KeyCode::Enter],
+            flag: vec![KeyCode::KeyF],
+            chord: vec![KeyCode::KeyD],
+        }
+    }
+}
+
+/// What a bound key does with the keyboard cursor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    /// Moves the cursor by a number of tiles on each axis
+    Move(i32, i32),
+    Reveal,
+    Flag,
+    Chord,
+}
@@ -0,0 +1,16 @@
+use crate::{resources::*, util::*};
+use bevy::{input::keyboard::KeyboardInput, prelude::*};
+
+/// The tile cursor moved with the keyboard, shown after keyboard use and hidden by the mouse or a
+/// touch. Must be used as a resource.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct KeyboardCursor {
+    pub cursor: Option<TileCursor>,
+}
+
+impl KeyboardCursor {
+    /// Does the cursor take the key of the event from the shortcuts
+    pub fn captures(&self, key_bindings: &KeyBindings, event: &KeyboardInput) -> bool {
+        self.cursor.is_some() && key_bindings.action(event.key_code).is_some()
+    }
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+/// An image shaping the boards, loaded before the game starts, see `mask_from_image`. Must be used
+/// as a resource.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct MaskImage {
+    /// The asset path of the image
+    pub path: String,
+
+    /// The image, once its loading started
+    pub handle: Option<Handle<Image>>,
+}
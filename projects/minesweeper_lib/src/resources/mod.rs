@@ -1,19 +1,45 @@
 mod board;
 mod board_assets;
 mod board_colors;
+mod board_generation_option;
 mod board_options;
 mod board_position_option;
+mod cursor_edge_option;
+mod game_stats;
+mod game_timer;
+mod gamepad_cursors;
+mod key_bindings;
+mod keyboard_cursor;
+mod mask_image;
+mod pending_restart;
+mod pending_resume;
+mod probability_overlay;
+mod replay_playback;
+mod replay_recorder;
+mod reveal_animation_option;
 mod sprite_material;
-mod tile;
-mod tile_map;
 mod tile_size_option;
 
 pub use board::*;
 pub use board_assets::*;
 pub use board_colors::*;
+pub use board_generation_option::*;
 pub use board_options::*;
 pub use board_position_option::*;
+pub use cursor_edge_option::*;
+pub use game_stats::*;
+pub use game_timer::*;
+pub use gamepad_cursors::*;
+pub use key_bindings::*;
+pub use keyboard_cursor::*;
+pub use mask_image::*;
+pub use pending_restart::*;
+pub use pending_resume::*;
+pub use probability_overlay::*;
+pub use replay_playback::*;
+pub use replay_recorder::*;
+pub use reveal_animation_option::*;
 pub use sprite_material::*;
-pub use tile::*;
-pub use tile_map::*;
 pub use tile_size_option::*;
+
+pub use minesweeper_core::{Tile, TileMap};
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Set when the game was left to be restarted. Must be used as a resource.
+#[derive(Debug, Clone, Default, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct PendingRestart {
+    pub requested: bool,
+}
@@ -0,0 +1,35 @@
+use crate::{resources::*, util::*};
+use bevy::prelude::*;
+
+/// A loaded game whose boards are spawned instead of new ones the next time the game is entered.
+/// Must be used as a resource.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct PendingResume {
+    /// The options, restored games and counters of the boards to spawn
+    pub boards: Vec<(BoardOptions, Game, BoardCounters)>,
+
+    /// Seconds elapsed in the saved game
+    pub elapsed: f32,
+}
+
+impl PendingResume {
+    /// Restores the boards of a saved game, failing when one of them is invalid
+    pub fn from_saved_game(saved_game: SavedGame) -> Result<Self, SaveError> {
+        let games = saved_game.restore()?;
+        let boards = saved_game
+            .boards
+            .into_iter()
+            .zip(games)
+            .map(|(saved_board, game)| (saved_board.options, game, saved_board.counters))
+            .collect();
+
+        Ok(Self {
+            boards,
+            elapsed: saved_game.elapsed,
+        })
+    }
+
+    pub fn is_pending(&self) -> bool {
+        !self.boards.is_empty()
+    }
+}
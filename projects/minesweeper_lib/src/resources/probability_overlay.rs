@@ -0,0 +1,17 @@
+use crate::components::*;
+use bevy::{prelude::*, utils::HashMap};
+
+/// State of the mine probability overlay. Must be used as a resource.
+#[derive(Debug, Clone, Default, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct ProbabilityOverlay {
+    /// Is the overlay shown
+    pub enabled: bool,
+
+    /// The number of covered tiles of each board when its covers were last tinted, a board is
+    /// missing when its covers are not tinted
+    pub tinted_covered_tile_counts: HashMap<Entity, usize>,
+
+    /// The marked tiles of each board when its covers were last tinted
+    pub tinted_marked_tiles: HashMap<Entity, Vec<Coordinates>>,
+}
@@ -0,0 +1,54 @@
+use crate::util::*;
+use bevy::prelude::*;
+
+/// Replays played back on boards instead of the player inputs. Must be used as a resource.
+#[derive(Debug, Clone, Resource)]
+pub struct ReplayPlayback {
+    /// The boards played back with the replays played on them. The board is `Entity::PLACEHOLDER`
+    /// until it is spawned.
+    pub boards: Vec<(Entity, SavedReplay)>,
+
+    /// The replay time reached, in seconds
+    pub position: f32,
+
+    /// The replay seconds played per second
+    pub speed: f32,
+
+    /// Is the playback paused
+    pub paused: bool,
+
+    /// Set when the boards must be spawned again at `position`, to start or scrub the playback
+    pub seek_requested: bool,
+}
+
+impl ReplayPlayback {
+    pub fn is_active(&self) -> bool {
+        !self.boards.is_empty()
+    }
+
+    /// The time of the last input of the replays
+    pub fn duration(&self) -> f32 {
+        self.boards
+            .iter()
+            .map(|(_, saved_replay)| saved_replay.replay.duration())
+            .fold(0., f32::max)
+    }
+
+    /// Moves the playback to a time, clamped to the replays
+    pub fn seek(&mut self, position: f32) {
+        self.position = position.clamp(0., self.duration());
+        self.seek_requested = true;
+    }
+}
+
+impl Default for ReplayPlayback {
+    fn default() -> Self {
+        Self {
+            boards: Vec::new(),
+            position: 0.,
+            speed: 1.,
+            paused: false,
+            seek_requested: false,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::util::*;
+use bevy::{prelude::*, utils::HashMap};
+
+/// Records the inputs made on every board. Must be used as a resource.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct ReplayRecorder {
+    /// The fixed time each recording started at and the replay recorded, by board
+    pub recordings: HashMap<Entity, (f32, Replay)>,
+}
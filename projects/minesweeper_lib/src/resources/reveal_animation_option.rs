@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How the covers of revealed tiles are removed
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Reflect)]
+pub enum RevealAnimationOption {
+    /// Every cover is removed at once
+    #[default]
+    Instant,
+
+    /// Covers are removed in a wave spreading from the revealed tile, each step of the opening
+    /// waits `step_delay` seconds more
+    Wave { step_delay: f32 },
+}
+
+impl RevealAnimationOption {
+    /// The delay before removing the cover of a tile revealed `distance` steps away from the
+    /// revealed tile
+    pub fn delay(&self, distance: u32) -> f32 {
+        match self {
+            Self::Instant => 0.,
+            Self::Wave { step_delay } => distance as f32 * step_delay,
+        }
+    }
+}
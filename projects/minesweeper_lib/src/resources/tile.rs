@@ -1,33 +0,0 @@
-use bevy::prelude::*;
-#[cfg(feature = "debug")]
-use colored::Colorize;
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect)]
-pub enum Tile {
-    Bomb,
-    BombNeighbor(u8),
-    Empty,
-}
-
-impl Tile {
-    pub const fn is_bomb(&self) -> bool {
-        matches!(self, Self::Bomb)
-    }
-
-    #[cfg(feature = "debug")]
-    pub fn console_output(&self) -> String {
-        format!(
-            "{}",
-            match self {
-                Tile::Bomb => "*".bright_red(),
-                Tile::BombNeighbor(v) => match v {
-                    1 => "1".cyan(),
-                    2 => "2".green(),
-                    3 => "3".yellow(),
-                    _ => v.to_string().red(),
-                },
-                Tile::Empty => " ".normal(),
-            }
-        )
-    }
-}
@@ -1,13 +1,15 @@
 use crate::{
     components::*,
-    config::{GameConfig, Vec2Config},
+    config::{Difficulty, GameConfig, Vec2Config},
     ext::*,
     resources::*,
     util::*,
-    BoardPlugin2, TypeRegistry,
+    BoardPlugin2, HudPlugin, ProbabilityOverlayPlugin, ReplayPlugin, SavePlugin, StatsPlugin,
+    TypeRegistry,
 };
 use bevy::{
     app::PluginGroupBuilder,
+    asset::LoadState,
     input::keyboard::{Key, KeyboardInput},
     log,
     prelude::*,
@@ -112,6 +114,7 @@ fn setup_board(
     mut commands: Commands,
     mut next_state: ResMut<NextState<AppState>>,
     board_options: Res<BoardOptions>,
+    mask_image: Option<Res<MaskImage>>,
     asset_server: Res<AssetServer>,
 ) {
     // Board assets
@@ -139,17 +142,128 @@ fn setup_board(
             texture: asset_server.load("sprites/flag.png"),
             color: board_options.colors.flag_color,
         },
+        wrong_flag_material: SpriteMaterial {
+            color: board_options.colors.bomb_color,
+            ..Default::default()
+        },
         bomb_material: SpriteMaterial {
             texture: asset_server.load("sprites/bomb.png"),
             color: board_options.colors.bomb_color,
         },
+        exploded_tile_material: SpriteMaterial {
+            color: board_options.colors.exploded_tile_color,
+            ..Default::default()
+        },
     });
-    // Plugin activation
+    // Plugin activation, once the mask image shaped the board
+    if mask_image.is_none() {
+        next_state.set(AppState::Loaded);
+    }
+}
+
+/// Shapes the board options like the mask image once it is loaded, then activates the plugins. The
+/// board is not shaped when the image can not be loaded or leaves no room for the bombs.
+fn load_mask_image(
+    mut commands: Commands,
+    mut mask_image: ResMut<MaskImage>,
+    mut board_options: ResMut<BoardOptions>,
+    mut next_state: ResMut<NextState<AppState>>,
+    asset_server: Res<AssetServer>,
+    images: Res<Assets<Image>>,
+) {
+    let path = mask_image.path.clone();
+    let handle = mask_image
+        .handle
+        .get_or_insert_with(|| asset_server.load(path.clone()));
+    match asset_server.load_state(handle.id()) {
+        LoadState::Loaded => match images.get(handle.id()).and_then(mask_from_image) {
+            None => log::error!("Could not read the pixels of the mask image {}", path),
+            Some(mask) => {
+                let masked_options = board_options.clone().with_mask(mask);
+                match masked_options.validate() {
+                    Ok(()) => *board_options = masked_options,
+                    Err(error) => {
+                        log::error!("The mask image {} can not shape the board: {}", path, error)
+                    }
+                }
+            }
+        },
+        LoadState::Failed(error) => {
+            log::error!("Could not load the mask image {}: {}", path, error)
+        }
+        LoadState::NotLoaded | LoadState::Loading => return,
+    }
+
+    commands.remove_resource::<MaskImage>();
     next_state.set(AppState::Loaded);
 }
 
+/// Builds the board options of the configuration, rejecting the ones which can not be played
+fn board_options(
+    config: &GameConfig,
+    colors: BoardColors,
+) -> Result<BoardOptions, BoardOptionsError> {
+    let custom = config.tile_count.is_some() || config.bomb_count.is_some();
+    let difficulty = config.difficulty.unwrap_or(if custom {
+        Difficulty::Custom
+    } else {
+        Difficulty::Intermediate
+    });
+    if custom && difficulty != Difficulty::Custom {
+        log::warn!(
+            "The tile count and bomb count are ignored by the {:?} difficulty",
+            difficulty
+        );
+    }
+
+    let default_options = BoardOptions::default().with_difficulty(Difficulty::Intermediate);
+    let board_options = BoardOptions {
+        bomb_count: config.bomb_count.unwrap_or(default_options.bomb_count),
+        map_size: config
+            .tile_count
+            .as_ref()
+            .map(|tile_count| (tile_count.x, tile_count.y))
+            .unwrap_or(default_options.map_size),
+        safe_start: true,
+        generation: config
+            .generation
+            .unwrap_or_default()
+            .option(config.max_generation_attempts.unwrap_or(1000)),
+        reveal_animation: RevealAnimationOption::Wave { step_delay: 0.015 },
+        tile_size: config
+            .tile_size
+            .as_ref()
+            .map(|tile_size| TileSizeOption::Adaptive {
+                min: tile_size.min,
+                max: tile_size.max,
+            })
+            .unwrap_or(default()),
+        tile_padding: config.tile_padding_size.unwrap_or(3.0),
+        colors,
+        seed: config.seed,
+        hud_height: 60.,
+        ..default()
+    }
+    .with_difficulty(difficulty);
+    let board_options = match &config.mask {
+        Some(art) => board_options.with_mask(Mask::from_ascii(art)),
+        None => board_options,
+    };
+
+    board_options.validate()?;
+    Ok(board_options)
+}
+
+/// Runs the game, unless the configuration describes a game which can not be played
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn run(config: GameConfig) {
+pub fn run(config: GameConfig) -> Result<(), BoardOptionsError> {
+    let colors: BoardColors = config
+        .color_config
+        .clone()
+        .map(|color_config| color_config.into())
+        .unwrap_or_default();
+    let board_options = board_options(&config, colors.clone())?;
+
     let resolution = config.resolution.unwrap_or(Vec2Config { x: 700., y: 800. });
 
     set_canvas_size(resolution.clone());
@@ -165,41 +279,84 @@ pub fn run(config: GameConfig) {
     app.add_plugins(BoardPlugin2 {
         running_state: AppState::InGame,
     });
+    app.add_plugins(HudPlugin {
+        running_state: AppState::InGame,
+        out_state: AppState::Out,
+    });
+    app.add_plugins(ProbabilityOverlayPlugin {
+        running_state: AppState::InGame,
+    });
+    app.add_plugins(SavePlugin {
+        running_state: AppState::InGame,
+    });
+    app.add_plugins(ReplayPlugin {
+        running_state: AppState::InGame,
+    });
+    app.add_plugins(StatsPlugin {
+        running_state: AppState::InGame,
+        out_state: AppState::Out,
+    });
 
     #[cfg(feature = "debug")]
     app.add_plugins(WorldInspectorPlugin::new());
 
     app.insert_state(AppState::Loading);
-
-    let colors: BoardColors = config
-        .color_config
-        .map(|color_config| color_config.into())
-        .unwrap_or_default();
+    app.add_sub_state::<GameState>();
 
     app.insert_resource(ClearColor(colors.background_color));
-    app.insert_resource(BoardOptions {
-        bomb_count: config.bomb_count.unwrap_or(40),
-        map_size: config
-            .tile_count
-            .map(|tile_count| (tile_count.x, tile_count.y))
-            .unwrap_or((20, 20)),
-        safe_start: true,
-        tile_size: config
-            .tile_size
-            .map(|tile_size| TileSizeOption::Adaptive {
-                min: tile_size.min,
-                max: tile_size.max,
-            })
-            .unwrap_or(default()),
-        tile_padding: config.tile_padding_size.unwrap_or(3.0),
-        colors,
-        ..default()
-    });
+    app.insert_resource(board_options);
+    match (config.mask, config.mask_image) {
+        (Some(_), Some(_)) => log::warn!("The mask image is ignored, the ASCII art mask is used"),
+        (None, Some(path)) => {
+            app.insert_resource(MaskImage { path, handle: None });
+        }
+        _ => {}
+    }
 
     app.add_systems(Startup, setup_board);
+    app.add_systems(
+        Update,
+        load_mask_image
+            .run_if(in_state(AppState::Loading))
+            .run_if(resource_exists::<MaskImage>),
+    );
     app.add_systems(Startup, startup_camera_system);
     app.add_systems(Startup, iteration_system);
     app.add_systems(FixedUpdate, state_handler);
 
     app.run();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_mask_shapes_the_board() {
+        let config = GameConfig {
+            bomb_count: Some(2),
+            mask: Some("#####\n#####\n ### ".to_string()),
+            mask_image: Some("ignored.png".to_string()),
+            ..default()
+        };
+        let options = board_options(&config, default()).unwrap();
+
+        assert_eq!(options.map_size, (5, 3));
+        assert_eq!(options.grid().tile_count(), 13);
+        assert_eq!(options.bomb_count, 2);
+    }
+
+    #[test]
+    fn ascii_mask_too_small_for_the_bombs_is_rejected() {
+        let config = GameConfig {
+            mask: Some("###\n###".to_string()),
+            ..default()
+        };
+        assert!(matches!(
+            board_options(&config, default()),
+            Err(BoardOptionsError::TooManyBombs { .. })
+        ));
+    }
 }
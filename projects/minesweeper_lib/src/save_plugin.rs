@@ -0,0 +1,24 @@
+use crate::{resources::*, systems::*};
+use bevy::{log, prelude::*};
+
+/// Saves the game in progress with `s` and resumes it with `l`. Saves go to a file in the user
+/// data directory on native and to the local storage on wasm. Needs the `HudPlugin` to restart the
+/// game and keep its time.
+pub struct SavePlugin<T> {
+    pub running_state: T,
+}
+
+impl<T: States> Plugin for SavePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PendingResume>();
+
+        app.add_systems(
+            Update,
+            (resume_game_timer, save_game_input, load_game_input)
+                .chain()
+                .run_if(in_state(self.running_state.clone())),
+        );
+
+        log::info!("Loaded Save Plugin");
+    }
+}
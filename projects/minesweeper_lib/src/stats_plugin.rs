@@ -0,0 +1,50 @@
+use crate::{components::*, resources::*, systems::*};
+use bevy::{log, prelude::*};
+
+/// Counts the finished games in statistics kept per difficulty across sessions, and shows them
+/// with `t` on the out state, where they can be reset. Statistics go to the save storage next to
+/// the saved game. Needs the `HudPlugin` to time the games.
+pub struct StatsPlugin<T> {
+    pub running_state: T,
+    /// The state the statistics screen is shown in
+    pub out_state: T,
+}
+
+impl<T: States> Plugin for StatsPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameStats::load());
+
+        app.add_systems(OnExit(self.out_state.clone()), Self::cleanup_stats_screen);
+
+        app.add_systems(
+            Update,
+            record_stats
+                .after(summarize_games)
+                .run_if(in_state(self.running_state.clone())),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                stats_screen_input,
+                reset_stats_button_input,
+                update_stats_screen,
+            )
+                .chain()
+                .run_if(in_state(self.out_state.clone())),
+        );
+
+        log::info!("Loaded Stats Plugin");
+    }
+}
+
+impl<T: States> StatsPlugin<T> {
+    fn cleanup_stats_screen(
+        mut commands: Commands,
+        stats_screens: Query<Entity, With<StatsScreen>>,
+    ) {
+        for stats_screen in stats_screens.iter() {
+            commands.entity(stats_screen).despawn_recursive();
+        }
+    }
+}
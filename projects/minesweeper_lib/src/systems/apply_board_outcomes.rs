@@ -0,0 +1,201 @@
+use crate::{components::*, events::*, resources::*, util::*};
+use bevy::{log, prelude::*};
+
+/// Renders the state changes of the games played on the boards: places the tiles of new layouts,
+/// spawns and removes flags, hands the covers of revealed tiles to the reveal wave and restores the
+/// covers and flags taken back by an undo
+#[allow(clippy::too_many_arguments)]
+pub fn apply_board_outcomes(
+    mut commands: Commands,
+    mut boards: Query<(&mut Board, &BoardOptions)>,
+    board_assets: Res<BoardAssets>,
+    children: Query<&Children>,
+    flags: Query<(), With<Flag>>,
+    mut sprites: Query<&mut Sprite>,
+    mut board_outcome_event_reader: EventReader<BoardOutcomeEvent>,
+    mut board_completed_event_writer: EventWriter<BoardCompletedEvent>,
+    mut bomb_explosion_event_writer: EventWriter<BombExplosionEvent>,
+) {
+    // The flags of a tile are drawn once its outcomes are all applied, several flags can be
+    // placed or removed at once
+    let mut flagged_tiles = Vec::new();
+
+    for event in board_outcome_event_reader.read() {
+        let (mut board, board_options) = match boards.get_mut(event.board) {
+            Ok(v) => v,
+            Err(e) => {
+                log::error!("{}", e);
+                continue;
+            }
+        };
+
+        match event.outcome {
+            Outcome::LaidOut { attempts, solvable } => {
+                let no_guess = matches!(
+                    board_options.generation,
+                    BoardGenerationOption::NoGuess { .. }
+                );
+                if no_guess && solvable {
+                    log::info!("Generated a no-guess layout in {} attempts", attempts);
+                } else if no_guess {
+                    log::warn!(
+                        "No no-guess layout found in {} attempts, using the closest one",
+                        attempts
+                    );
+                }
+                spawn_tile_kinds(&mut commands, &mut board, &board_assets);
+            }
+            Outcome::Flagged(coordinates) | Outcome::Unflagged(coordinates) => {
+                flagged_tiles.push((event.board, coordinates));
+            }
+            Outcome::Revealed {
+                coordinates,
+                distance,
+            } => {
+                if let Some(cover) = board.reveal_cover(&coordinates) {
+                    log::debug!("Uncovered tile {} (entity: {:?})", coordinates, cover);
+                    commands.entity(cover).insert(Uncover {
+                        delay: board_options.reveal_animation.delay(distance),
+                    });
+                }
+            }
+            Outcome::Covered(coordinates) => {
+                spawn_cover(&mut commands, &mut board, &board_assets, coordinates);
+            }
+            Outcome::Exploded(coordinates) => {
+                log::info!("Boom !");
+                bomb_explosion_event_writer.send(BombExplosionEvent {
+                    board: event.board,
+                    coordinates,
+                });
+            }
+            Outcome::Defused(coordinates) => {
+                log::info!("Explosion undone");
+                if let Ok(mut sprite) = sprites.get_mut(board.entities[&coordinates].root) {
+                    sprite.color = board_assets.tile_material.color;
+                }
+                let restored_flags =
+                    restore_exploded_board(&mut commands, &mut board, &board_assets);
+                flagged_tiles.extend(restored_flags.map(|coordinates| (event.board, coordinates)));
+            }
+            Outcome::Won => {
+                log::info!("Board completed");
+                board_completed_event_writer.send(BoardCompletedEvent { board: event.board });
+            }
+        }
+    }
+
+    flagged_tiles.sort();
+    flagged_tiles.dedup();
+    for (board_entity, coordinates) in flagged_tiles {
+        if let Ok((board, _)) = boards.get(board_entity) {
+            draw_flags(
+                &mut commands,
+                board,
+                &board_assets,
+                &children,
+                &flags,
+                coordinates,
+            );
+        }
+    }
+}
+
+/// Spawns the bombs and bomb counters of a board whose bombs were just placed
+fn spawn_tile_kinds(commands: &mut Commands, board: &mut Board, board_assets: &BoardAssets) {
+    log::info!("Laid out the board");
+    #[cfg(feature = "debug")]
+    log::info!("{}", board.game.tile_map().console_output());
+
+    let size = board.tile_size - board.tile_padding;
+    let Board { game, entities, .. } = board;
+    for (coordinates, tile_entities) in entities.iter_mut() {
+        let tile = &game.tile_map().map()[coordinates.y as usize][coordinates.x as usize];
+        let mut entity_commands = commands.entity(tile_entities.root);
+        tile_entities.kind = spawn_tile_kind(&mut entity_commands, tile, board_assets, size);
+    }
+}
+
+/// Spawns the cover of a tile covered again, unless it still has one
+fn spawn_cover(
+    commands: &mut Commands,
+    board: &mut Board,
+    board_assets: &BoardAssets,
+    coordinates: Coordinates,
+) -> Option<Entity> {
+    if board.covered_tiles.contains_key(&coordinates) {
+        return None;
+    }
+
+    let size = board.tile_size - board.tile_padding;
+    let tile_entities = board.entities.get_mut(&coordinates)?;
+    let mut cover = None;
+    commands.entity(tile_entities.root).with_children(|parent| {
+        cover = Some(
+            parent
+                .spawn(board_assets.cover_sprite_bundle(size))
+                .insert(Name::new("Tile Cover"))
+                .id(),
+        );
+    });
+    let cover = cover?;
+    tile_entities.cover = cover;
+    board.covered_tiles.insert(coordinates, cover);
+    Some(cover)
+}
+
+/// Takes back the rendering of `explode_board`: covers the revealed bombs again, returning the
+/// flagged tiles whose flags must be drawn again in place of crosses
+fn restore_exploded_board(
+    commands: &mut Commands,
+    board: &mut Board,
+    board_assets: &BoardAssets,
+) -> impl Iterator<Item = Coordinates> {
+    let covered: Vec<Coordinates> = board
+        .game
+        .tile_map()
+        .iter_coordinates()
+        .filter(|coordinates| board.game.is_covered(*coordinates))
+        .collect();
+    for coordinates in &covered {
+        spawn_cover(commands, board, board_assets, *coordinates);
+    }
+
+    let flagged: Vec<Coordinates> = covered
+        .into_iter()
+        .filter(|coordinates| board.game.is_flagged(*coordinates))
+        .collect();
+    flagged.into_iter()
+}
+
+/// Replaces the flags drawn on the cover of a tile with as many as it holds, the cover of a
+/// revealed tile loses its flags while it is uncovered
+fn draw_flags(
+    commands: &mut Commands,
+    board: &Board,
+    board_assets: &BoardAssets,
+    children: &Query<&Children>,
+    flags: &Query<(), With<Flag>>,
+    coordinates: Coordinates,
+) {
+    let Some(cover) = board
+        .entities
+        .get(&coordinates)
+        .map(|entities| entities.cover)
+    else {
+        return;
+    };
+
+    for child in children.get(cover).into_iter().flatten() {
+        if flags.contains(*child) {
+            commands.entity(*child).despawn_recursive();
+        }
+    }
+
+    let flag_count = board.game.flag_count(coordinates);
+    if flag_count > 0 {
+        commands.entity(cover).with_children(|parent| {
+            spawn_flag(parent, board_assets, board.tile_size, flag_count);
+        });
+    }
+}
@@ -1,19 +1,43 @@
 use crate::{
     components::Coordinates,
     config::Vec2Config,
-    resources::{Board, BoardOptions, BoardPositionOption},
-    util::{get_canvas_size, set_canvas_size, Bounds2},
+    resources::{Board, BoardOptions},
+    util::{get_canvas_size, set_canvas_size, Bounds2, TileLayout},
 };
-use bevy::{ecs::query::QueryEntityError, log, math::U16Vec2, prelude::*};
+use bevy::{ecs::query::QueryEntityError, log, prelude::*};
 
 pub fn canvas_size_updater(
-    mut board: ResMut<Board>,
-    board_options: Option<Res<BoardOptions>>,
+    mut boards: Query<(&mut Board, &BoardOptions)>,
     coordinates: Query<&Coordinates>,
     mut windows: Query<&mut Window>,
     mut transforms: Query<&mut Transform>,
     mut sprites: Query<&mut Sprite>,
     mut texts: Query<Option<&mut Text>>,
+) -> Result<(), QueryEntityError> {
+    for (mut board, board_options) in boards.iter_mut() {
+        update_board(
+            &mut board,
+            board_options,
+            &coordinates,
+            &mut windows,
+            &mut transforms,
+            &mut sprites,
+            &mut texts,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Keeps the sizes of the canvas, the window and a board in sync, then lays the board out again
+fn update_board(
+    board: &mut Board,
+    board_options: &BoardOptions,
+    coordinates: &Query<&Coordinates>,
+    windows: &mut Query<&mut Window>,
+    transforms: &mut Query<&mut Transform>,
+    sprites: &mut Query<&mut Sprite>,
+    texts: &mut Query<Option<&mut Text>>,
 ) -> Result<(), QueryEntityError> {
     {
         // `board.canvas_size`, `get_canvas_size()`, and `window.resolution.size()`
@@ -49,28 +73,15 @@ pub fn canvas_size_updater(
         }
     }
 
-    let board_options = BoardOptions::optional_resource_or_default(board_options);
-
     let tile_padding = board_options.tile_padding;
-    let tile_size = board_options.compute_tile_size(
-        &board.canvas_size,
-        U16Vec2::new(board.tile_map.width(), board.tile_map.height()),
-    );
+    let tile_size = board_options.compute_tile_size(&board.canvas_size, board.map_size());
     log::info!("Updating tile_size to {}", tile_size);
     board.tile_size = tile_size;
 
-    let board_size = Vec2::new(
-        board.tile_map.width() as f32 * tile_size,
-        board.tile_map.height() as f32 * tile_size,
-    );
+    let board_size = board.topology().size_in_tiles(board.map_size()) * tile_size;
     log::info!("Updating board_size to {}", board_size);
 
-    let board_position = match board_options.position {
-        BoardPositionOption::Centered { offset } => {
-            Vec3::new(-(board_size.x / 2.), -(board_size.y / 2.), 0.) + offset
-        }
-        BoardPositionOption::Custom(p) => p,
-    };
+    let board_position = board_options.compute_board_position(board_size);
     log::info!("Updating board_position to {}", board_position);
 
     board.bounds = Bounds2 {
@@ -87,15 +98,12 @@ pub fn canvas_size_updater(
     let mut board_background_sprite = sprites.get_mut(board.background_entity)?;
     board_background_sprite.custom_size = Some(board_size);
 
-    for entity in board.entities.iter() {
+    for entity in board.entities.values() {
         let coordinates = coordinates.get(entity.root)?;
 
         let mut root_transform = transforms.get_mut(entity.root)?;
-        *root_transform = Transform::from_xyz(
-            (coordinates.x as f32 * tile_size) + (tile_size / 2.),
-            (coordinates.y as f32 * tile_size) + (tile_size / 2.),
-            1.,
-        );
+        *root_transform =
+            Transform::from_translation(board.tile_translation(*coordinates).extend(1.));
 
         let mut root_sprite = sprites.get_mut(entity.root)?;
         root_sprite.custom_size = Some(Vec2::splat(tile_size - tile_padding));
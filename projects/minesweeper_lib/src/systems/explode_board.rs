@@ -0,0 +1,85 @@
+use crate::{components::*, events::*, resources::*};
+use bevy::{log, prelude::*};
+
+/// Renders a board lost to an explosion: reveals every unmarked bomb, crosses out wrongly marked
+/// tiles and highlights the exploded tiles
+pub fn explode_board(
+    mut commands: Commands,
+    mut boards: Query<&mut Board>,
+    board_assets: Res<BoardAssets>,
+    mut bomb_explosion_event_reader: EventReader<BombExplosionEvent>,
+    children: Query<&Children>,
+    mut sprites: Query<&mut Sprite>,
+) {
+    // A chord can explode several bombs at once, the bombs are revealed once per board
+    let mut exploded_boards = Vec::new();
+
+    for event in bomb_explosion_event_reader.read() {
+        let board = match boards.get(event.board) {
+            Ok(board) => board,
+            Err(e) => {
+                log::error!("{}", e);
+                continue;
+            }
+        };
+        log::info!("Board lost at {}", event.coordinates);
+
+        if let Ok(mut sprite) = sprites.get_mut(board.entities[&event.coordinates].root) {
+            sprite.color = board_assets.exploded_tile_material.color;
+        }
+
+        if !exploded_boards.contains(&event.board) {
+            exploded_boards.push(event.board);
+        }
+    }
+
+    for board_entity in exploded_boards {
+        if let Ok(mut board) = boards.get_mut(board_entity) {
+            reveal_bombs(&mut commands, &mut board, &board_assets, &children);
+        }
+    }
+}
+
+fn reveal_bombs(
+    commands: &mut Commands,
+    board: &mut Board,
+    board_assets: &BoardAssets,
+    children: &Query<&Children>,
+) {
+    let size = board.tile_size - board.tile_padding;
+    for y in 0..board.game.tile_map().height() {
+        for x in 0..board.game.tile_map().width() {
+            let coordinates = Coordinates { x, y };
+            // Tiles with fewer flags than bombs reveal their bombs, the ones with more flags are
+            // wrongly flagged
+            let bomb_count = board.game.tile_map().bombs_at(coordinates);
+            let flag_count = board.game.flag_count(coordinates);
+
+            if bomb_count > flag_count {
+                if let Some(cover) = board.covered_tiles.remove(&coordinates) {
+                    commands.entity(cover).despawn_recursive();
+                }
+            } else if flag_count > bomb_count {
+                let cover = match board.covered_tiles.get(&coordinates) {
+                    None => continue,
+                    Some(cover) => *cover,
+                };
+
+                // Replace the flag with a cross
+                if let Ok(flags) = children.get(cover) {
+                    for flag in flags.iter() {
+                        commands.entity(*flag).despawn_recursive();
+                    }
+                }
+                commands.entity(cover).with_children(|parent| {
+                    for bundle in board_assets.wrong_flag_sprite_bundles(size) {
+                        parent
+                            .spawn(bundle)
+                            .insert(Name::new("Wrong Flag"))
+                            .insert(Flag);
+                    }
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{components::*, events::*, resources::*, util::*};
+use bevy::{
+    input::gamepad::{GamepadButtonInput, GamepadButtonType},
+    log,
+    prelude::*,
+};
+
+/// How far the left stick must be pushed to move the cursor
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// Moves a tile cursor per gamepad with the d-pad or the left stick, held directions repeating
+/// faster and faster, and plays the tile under it: A reveals, X flags and Y chords. With several
+/// boards, the gamepads take the boards from left to right.
+#[allow(clippy::too_many_arguments)]
+pub fn gamepad_input(
+    mut commands: Commands,
+    boards: Query<(Entity, &Board, &BoardOptions)>,
+    mut transforms: Query<&mut Transform, With<Cover>>,
+    board_assets: Res<BoardAssets>,
+    gamepads: Res<Gamepads>,
+    buttons: Res<ButtonInput<GamepadButton>>,
+    axes: Res<Axis<GamepadAxis>>,
+    time: Res<Time>,
+    mut gamepad_cursors: ResMut<GamepadCursors>,
+    mut gamepad_button_event_reader: EventReader<GamepadButtonInput>,
+    mut tile_trigger_event_writer: EventWriter<TileTriggerEvent>,
+    mut tile_mark_event_writer: EventWriter<TileMarkEvent>,
+    mut tile_chord_event_writer: EventWriter<TileChordEvent>,
+) {
+    // Forget the cursors of disconnected gamepads and despawned boards
+    gamepad_cursors.cursors.retain(|gamepad, gamepad_cursor| {
+        let board_exists = boards.contains(gamepad_cursor.cursor.board);
+        if board_exists && !gamepads.contains(*gamepad) {
+            commands
+                .entity(gamepad_cursor.cursor.highlight)
+                .despawn_recursive();
+        }
+        board_exists && gamepads.contains(*gamepad)
+    });
+
+    let mut ordered_boards: Vec<_> = boards.iter().collect();
+    ordered_boards
+        .sort_by(|(_, a, _), (_, b, _)| a.bounds.position.x.total_cmp(&b.bounds.position.x));
+    let mut ordered_gamepads: Vec<_> = gamepads.iter().collect();
+    ordered_gamepads.sort_by_key(|gamepad| gamepad.id);
+
+    let pressed_buttons: Vec<GamepadButton> = gamepad_button_event_reader
+        .read()
+        .filter(|event| event.state.is_pressed())
+        .map(|event| event.button)
+        .collect();
+
+    for (index, gamepad) in ordered_gamepads.into_iter().enumerate() {
+        let direction = held_direction(gamepad, &buttons, &axes);
+        let buttons_pressed: Vec<GamepadButtonType> = pressed_buttons
+            .iter()
+            .filter(|button| button.gamepad == gamepad)
+            .map(|button| button.button_type)
+            .collect();
+
+        let gamepad_cursor = match gamepad_cursors.cursors.get_mut(&gamepad) {
+            Some(gamepad_cursor) => gamepad_cursor,
+            None => {
+                // The first use of a gamepad only shows its cursor
+                if direction == (0, 0) && buttons_pressed.is_empty() {
+                    continue;
+                }
+                let Some((_, board, _)) = ordered_boards.get(index % ordered_boards.len().max(1))
+                else {
+                    continue;
+                };
+                let Some(coordinates) = board.game.tile_map().grid().center_tile() else {
+                    continue;
+                };
+                let cursor = TileCursor::spawn(&mut commands, board, &board_assets, coordinates);
+                gamepad_cursors.cursors.insert(
+                    gamepad,
+                    GamepadCursor {
+                        cursor,
+                        repeat: CursorRepeat::held(direction),
+                    },
+                );
+                continue;
+            }
+        };
+
+        let Ok((board_entity, board, board_options)) = boards.get(gamepad_cursor.cursor.board)
+        else {
+            continue;
+        };
+
+        if let Some(step) = gamepad_cursor
+            .repeat
+            .update(direction, time.delta_seconds())
+        {
+            gamepad_cursor
+                .cursor
+                .move_by(board, board_options, step, &mut transforms);
+        }
+        // Revealing the tile under the cursor moves the highlight under its number
+        gamepad_cursor.cursor.place(board, &mut transforms);
+
+        if !board.is_playing() {
+            continue;
+        }
+        let coordinates = gamepad_cursor.cursor.coordinates;
+        for button_type in buttons_pressed {
+            match button_type {
+                GamepadButtonType::South => {
+                    log::info!("Trying to uncover tile on {}", coordinates);
+                    tile_trigger_event_writer.send(TileTriggerEvent {
+                        board: board_entity,
+                        coordinates,
+                    });
+                }
+                GamepadButtonType::West => {
+                    log::info!("Trying to mark tile on {}", coordinates);
+                    tile_mark_event_writer.send(TileMarkEvent {
+                        board: board_entity,
+                        coordinates,
+                    });
+                }
+                GamepadButtonType::North => {
+                    log::info!("Trying to chord tile on {}", coordinates);
+                    tile_chord_event_writer.send(TileChordEvent {
+                        board: board_entity,
+                        coordinates,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Returns the direction held with the d-pad or the left stick of a gamepad
+fn held_direction(
+    gamepad: Gamepad,
+    buttons: &ButtonInput<GamepadButton>,
+    axes: &Axis<GamepadAxis>,
+) -> (i32, i32) {
+    let button = |button_type| buttons.pressed(GamepadButton::new(gamepad, button_type)) as i32;
+    let stick = |axis_type| {
+        let value = axes
+            .get(GamepadAxis::new(gamepad, axis_type))
+            .unwrap_or_default();
+        if value > STICK_THRESHOLD {
+            1
+        } else if value < -STICK_THRESHOLD {
+            -1
+        } else {
+            0
+        }
+    };
+
+    let x = button(GamepadButtonType::DPadRight) - button(GamepadButtonType::DPadLeft)
+        + stick(GamepadAxisType::LeftStickX);
+    let y = button(GamepadButtonType::DPadUp) - button(GamepadButtonType::DPadDown)
+        + stick(GamepadAxisType::LeftStickY);
+    (x.clamp(-1, 1), y.clamp(-1, 1))
+}
@@ -0,0 +1,19 @@
+use crate::resources::*;
+use bevy::{
+    input::gamepad::{GamepadButtonInput, GamepadButtonType},
+    log,
+    prelude::*,
+};
+
+/// Requests a restart when Start is pressed on a gamepad
+pub fn gamepad_reset_input(
+    mut pending_restart: ResMut<PendingRestart>,
+    mut gamepad_button_event_reader: EventReader<GamepadButtonInput>,
+) {
+    for event in gamepad_button_event_reader.read() {
+        if event.button.button_type == GamepadButtonType::Start && event.state.is_pressed() {
+            log::info!("Restarting game");
+            pending_restart.requested = true;
+        }
+    }
+}
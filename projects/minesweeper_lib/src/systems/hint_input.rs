@@ -0,0 +1,65 @@
+use crate::{components::*, events::*, resources::*};
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        touch::{TouchPhase, Touches},
+    },
+    log,
+    prelude::*,
+};
+
+/// Requests hints with `h` (highlight) and `H` (highlight and uncover) on every board, or a two
+/// finger tap on the touched board
+#[allow(clippy::too_many_arguments)]
+pub fn hint_input(
+    mut commands: Commands,
+    windows: Query<&Window>,
+    touches: Res<Touches>,
+    mut boards: Query<(Entity, &Board, &mut TouchInterpretationComponent)>,
+    key_bindings: Res<KeyBindings>,
+    keyboard_cursor: Res<KeyboardCursor>,
+    mut key_event_reader: EventReader<KeyboardInput>,
+    mut touch_event_reader: EventReader<TouchInput>,
+    mut hint_request_event_writer: EventWriter<HintRequestEvent>,
+) {
+    for event in key_event_reader.read() {
+        if !event.state.is_pressed() || keyboard_cursor.captures(&key_bindings, event) {
+            continue;
+        }
+
+        let uncover = match &event.logical_key {
+            Key::Character(character) if character.as_str() == "h" => false,
+            Key::Character(character) if character.as_str() == "H" => true,
+            _ => continue,
+        };
+
+        for (board, _, _) in boards.iter().filter(|(_, board, _)| board.is_playing()) {
+            log::info!("Requesting a hint (uncover: {})", uncover);
+            hint_request_event_writer.send(HintRequestEvent { board, uncover });
+        }
+    }
+
+    for event in touch_event_reader.read() {
+        if event.phase != TouchPhase::Started || touches.iter().count() < 2 {
+            continue;
+        }
+
+        let window = windows.get_single().unwrap();
+        for (board_entity, board, mut touch_interpreter) in boards.iter_mut() {
+            // The second finger turns the pending touch into a hint gesture
+            if let Some(touch_interpretation_data) = touch_interpreter.data.take() {
+                commands
+                    .entity(touch_interpretation_data.cover_entity)
+                    .despawn_recursive();
+            }
+
+            if board.is_playing() && board.mouse_position(window, event.position).is_some() {
+                log::info!("Requesting a hint");
+                hint_request_event_writer.send(HintRequestEvent {
+                    board: board_entity,
+                    uncover: false,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{components::*, events::*, resources::*, util::*};
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        mouse::MouseButtonInput,
+    },
+    log,
+    prelude::*,
+};
+
+/// Moves the keyboard cursor and plays the tile under it with the key bindings. The cursor shows
+/// up on the board under the mouse, or the first one, and the mouse or a touch hides it.
+#[allow(clippy::too_many_arguments)]
+pub fn keyboard_cursor_input(
+    mut commands: Commands,
+    windows: Query<&Window>,
+    boards: Query<(&Board, &BoardOptions)>,
+    mut transforms: Query<&mut Transform, With<Cover>>,
+    board_assets: Res<BoardAssets>,
+    key_bindings: Res<KeyBindings>,
+    mut keyboard_cursor: ResMut<KeyboardCursor>,
+    mut key_event_reader: EventReader<KeyboardInput>,
+    mut cursor_moved_event_reader: EventReader<CursorMoved>,
+    mut button_event_reader: EventReader<MouseButtonInput>,
+    mut touch_event_reader: EventReader<TouchInput>,
+    mut tile_trigger_event_writer: EventWriter<TileTriggerEvent>,
+    mut tile_mark_event_writer: EventWriter<TileMarkEvent>,
+    mut tile_chord_event_writer: EventWriter<TileChordEvent>,
+) {
+    // The board of the cursor was despawned along with its highlight
+    if let Some(cursor) = keyboard_cursor.cursor {
+        if boards.get(cursor.board).is_err() {
+            keyboard_cursor.cursor = None;
+        }
+    }
+
+    let pointer_used = cursor_moved_event_reader.read().count() > 0
+        || button_event_reader.read().count() > 0
+        || touch_event_reader.read().count() > 0;
+    if pointer_used {
+        if let Some(cursor) = keyboard_cursor.cursor.take() {
+            commands.entity(cursor.highlight).despawn_recursive();
+        }
+    }
+
+    for event in key_event_reader.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+        let action = match key_bindings.action(event.key_code) {
+            Some(action) => action,
+            None => continue,
+        };
+
+        let mut cursor = match keyboard_cursor.cursor {
+            Some(cursor) => cursor,
+            None => {
+                // Letters are left to the shortcuts until the cursor is shown
+                if !matches!(event.logical_key, Key::Character(_)) {
+                    keyboard_cursor.cursor =
+                        show_cursor(&mut commands, windows.single(), &boards, &board_assets);
+                }
+                continue;
+            }
+        };
+        let (board, board_options) = match boards.get(cursor.board) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let coordinates = cursor.coordinates;
+        match action {
+            KeyAction::Move(x, y) => {
+                cursor.move_by(board, board_options, (x, y), &mut transforms);
+                keyboard_cursor.cursor = Some(cursor);
+            }
+            _ if !board.is_playing() => {}
+            KeyAction::Reveal => {
+                log::info!("Trying to uncover tile on {}", coordinates);
+                tile_trigger_event_writer.send(TileTriggerEvent {
+                    board: cursor.board,
+                    coordinates,
+                });
+            }
+            KeyAction::Flag => {
+                log::info!("Trying to mark tile on {}", coordinates);
+                tile_mark_event_writer.send(TileMarkEvent {
+                    board: cursor.board,
+                    coordinates,
+                });
+            }
+            KeyAction::Chord => {
+                log::info!("Trying to chord tile on {}", coordinates);
+                tile_chord_event_writer.send(TileChordEvent {
+                    board: cursor.board,
+                    coordinates,
+                });
+            }
+        }
+    }
+
+    // Revealing the tile under the cursor moves the highlight under its number
+    if let Some(cursor) = keyboard_cursor.cursor {
+        if let Ok((board, _)) = boards.get(cursor.board) {
+            cursor.place(board, &mut transforms);
+        }
+    }
+}
+
+/// Shows the cursor on the tile under the mouse, or on the tile closest to the middle of the first
+/// board
+fn show_cursor(
+    commands: &mut Commands,
+    window: &Window,
+    boards: &Query<(&Board, &BoardOptions)>,
+    board_assets: &BoardAssets,
+) -> Option<TileCursor> {
+    let hovered = window.cursor_position().and_then(|cursor_position| {
+        boards.iter().find_map(|(board, _)| {
+            board
+                .mouse_position(window, cursor_position)
+                .map(|coordinates| (board, coordinates))
+        })
+    });
+    let (board, coordinates) = hovered.or_else(|| {
+        let (board, _) = boards.iter().next()?;
+        let coordinates = board.game.tile_map().grid().center_tile()?;
+        Some((board, coordinates))
+    })?;
+
+    Some(TileCursor::spawn(
+        commands,
+        board,
+        board_assets,
+        coordinates,
+    ))
+}
@@ -0,0 +1,37 @@
+use crate::{resources::*, util::*};
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    log,
+    prelude::*,
+};
+
+/// Loads the saved game with `l` and restarts the game to spawn its boards
+pub fn load_game_input(
+    mut pending_resume: ResMut<PendingResume>,
+    mut pending_restart: ResMut<PendingRestart>,
+    key_bindings: Res<KeyBindings>,
+    keyboard_cursor: Res<KeyboardCursor>,
+    mut key_event_reader: EventReader<KeyboardInput>,
+) {
+    for event in key_event_reader.read() {
+        match &event.logical_key {
+            Key::Character(character) if character.as_str() == "l" => {}
+            _ => continue,
+        }
+        if !event.state.is_pressed() || keyboard_cursor.captures(&key_bindings, event) {
+            continue;
+        }
+
+        let loaded = read_save(SAVED_GAME_NAME)
+            .and_then(|ron| SavedGame::from_ron(&ron))
+            .and_then(PendingResume::from_saved_game);
+        match loaded {
+            Ok(loaded) => {
+                log::info!("Loaded a saved game of {} boards", loaded.boards.len());
+                *pending_resume = loaded;
+                pending_restart.requested = true;
+            }
+            Err(error) => log::error!("Could not load the saved game: {}", error),
+        }
+    }
+}
@@ -1,42 +1,28 @@
 use crate::{events::*, resources::*};
-use bevy::{log, prelude::*};
+use bevy::prelude::*;
 
 pub fn mark_tiles(
-    mut commands: Commands,
-    mut board: ResMut<Board>,
-    board_assets: Res<BoardAssets>,
+    mut boards: Query<&mut Board>,
     mut tile_mark_event_reader: EventReader<TileMarkEvent>,
-    query: Query<&Children>,
+    mut board_outcome_event_writer: EventWriter<BoardOutcomeEvent>,
 ) {
     for event in tile_mark_event_reader.read() {
-        if let Some((entity, mark)) = board.try_toggle_mark(&event.0) {
-            if mark {
-                commands.entity(*entity).with_children(|parent| {
-                    let mut child = parent.spawn(SpriteBundle {
-                        texture: board_assets.flag_material.texture.clone(),
-                        sprite: Sprite {
-                            custom_size: Some(Vec2::splat(board.tile_size)),
-                            color: board_assets.flag_material.color,
-                            ..Default::default()
-                        },
-                        transform: Transform::from_xyz(0., 0., 1.),
-                        ..Default::default()
-                    });
-                    child.insert(Name::new("Flag"));
-                });
-            } else {
-                let children = match query.get(*entity) {
-                    Ok(children) => children,
-                    Err(error) => {
-                        log::error!("Failed to retrieve flag entity components: {}", error);
-                        continue;
-                    }
-                };
+        let mut board = match boards.get_mut(event.board) {
+            Ok(board) if board.is_playing() => board,
+            _ => continue,
+        };
 
-                for child in children.iter() {
-                    commands.entity(*child).despawn_recursive();
-                }
-            }
+        let outcomes = board.game.toggle_flag(event.coordinates);
+        board.counters.clicks += 1;
+        if !outcomes.is_empty() {
+            board.counters.useful_clicks += 1;
         }
+
+        board_outcome_event_writer.send_batch(outcomes.into_iter().map(|outcome| {
+            BoardOutcomeEvent {
+                board: event.board,
+                outcome,
+            }
+        }));
     }
 }
@@ -1,13 +1,71 @@
+mod apply_board_outcomes;
 mod canvas_size_updater;
+mod explode_board;
+mod gamepad_input;
+mod gamepad_reset_input;
+mod hint_input;
+mod keyboard_cursor_input;
+mod load_game_input;
 mod mark_tiles;
 mod mouse_input;
+mod play_replay;
+mod read_tile_chord_event;
 mod read_tile_trigger_event;
+mod record_replay;
+mod record_stats;
+mod replay_input;
+mod request_hint;
+mod reset_button_input;
+mod reset_stats_button_input;
+mod resume_game_timer;
+mod save_game_input;
+mod save_replays;
+mod stats_screen_input;
+mod summarize_games;
+mod toggle_probability_overlay;
 mod touch_input;
 mod uncover_tiles;
+mod undo_actions;
+mod undo_input;
+mod update_game_state;
+mod update_game_timer;
+mod update_hud;
+mod update_probability_overlay;
+mod update_stats_screen;
+mod update_wrap_ghosts;
 
+pub use apply_board_outcomes::*;
 pub use canvas_size_updater::*;
+pub use explode_board::*;
+pub use gamepad_input::*;
+pub use gamepad_reset_input::*;
+pub use hint_input::*;
+pub use keyboard_cursor_input::*;
+pub use load_game_input::*;
 pub use mark_tiles::*;
 pub use mouse_input::*;
+pub use play_replay::*;
+pub use read_tile_chord_event::*;
 pub use read_tile_trigger_event::*;
+pub use record_replay::*;
+pub use record_stats::*;
+pub use replay_input::*;
+pub use request_hint::*;
+pub use reset_button_input::*;
+pub use reset_stats_button_input::*;
+pub use resume_game_timer::*;
+pub use save_game_input::*;
+pub use save_replays::*;
+pub use stats_screen_input::*;
+pub use summarize_games::*;
+pub use toggle_probability_overlay::*;
 pub use touch_input::*;
 pub use uncover_tiles::*;
+pub use undo_actions::*;
+pub use undo_input::*;
+pub use update_game_state::*;
+pub use update_game_timer::*;
+pub use update_hud::*;
+pub use update_probability_overlay::*;
+pub use update_stats_screen::*;
+pub use update_wrap_ghosts::*;
@@ -7,10 +7,12 @@ use bevy::{
 
 pub fn mouse_input(
     windows: Query<&Window>,
-    board: Res<Board>,
+    boards: Query<(Entity, &Board)>,
+    buttons: Res<ButtonInput<MouseButton>>,
     mut button_event_reader: EventReader<MouseButtonInput>,
     mut tile_trigger_event_writer: EventWriter<TileTriggerEvent>,
     mut tile_mark_event_writer: EventWriter<TileMarkEvent>,
+    mut tile_chord_event_writer: EventWriter<TileChordEvent>,
 ) {
     let window = windows.get_single().unwrap();
 
@@ -29,16 +31,36 @@ pub fn mouse_input(
                 cursor_position
             );
 
-            let tile_coordinates = board.mouse_position(window, cursor_position);
-            if let Some(tile_coordinates) = tile_coordinates {
+            // Boards do not overlap, the cursor is on at most one of them
+            let target = boards.iter().find_map(|(board_entity, board)| {
+                board
+                    .mouse_position(window, cursor_position)
+                    .filter(|_| board.is_playing())
+                    .map(|tile_coordinates| (board_entity, tile_coordinates))
+            });
+            if let Some((board, coordinates)) = target {
+                // Pressing left and right together chords like a middle click
+                let chord = match event.button {
+                    MouseButton::Left => buttons.pressed(MouseButton::Right),
+                    MouseButton::Right => buttons.pressed(MouseButton::Left),
+                    MouseButton::Middle => true,
+                    _ => false,
+                };
+
+                if chord {
+                    log::info!("Trying to chord tile on {}", coordinates);
+                    tile_chord_event_writer.send(TileChordEvent { board, coordinates });
+                    continue;
+                }
+
                 match event.button {
                     MouseButton::Left => {
-                        log::info!("Trying to uncover tile on {}", tile_coordinates);
-                        tile_trigger_event_writer.send(TileTriggerEvent(tile_coordinates));
+                        log::info!("Trying to uncover tile on {}", coordinates);
+                        tile_trigger_event_writer.send(TileTriggerEvent { board, coordinates });
                     }
                     MouseButton::Right => {
-                        log::info!("Trying to mark tile on {}", tile_coordinates);
-                        tile_mark_event_writer.send(TileMarkEvent(tile_coordinates));
+                        log::info!("Trying to mark tile on {}", coordinates);
+                        tile_mark_event_writer.send(TileMarkEvent { board, coordinates });
                     }
                     _ => {}
                 }
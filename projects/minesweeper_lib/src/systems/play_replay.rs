@@ -0,0 +1,52 @@
+use crate::{events::*, resources::*, util::*};
+use bevy::prelude::*;
+
+/// Advances the playback and sends the tile events of the inputs reached, like the player would
+pub fn play_replay(
+    time: Res<Time<Fixed>>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut tile_trigger_event_writer: EventWriter<TileTriggerEvent>,
+    mut tile_chord_event_writer: EventWriter<TileChordEvent>,
+    mut tile_mark_event_writer: EventWriter<TileMarkEvent>,
+    mut undo_request_event_writer: EventWriter<UndoRequestEvent>,
+) {
+    if !playback.is_active() || playback.paused || playback.seek_requested {
+        return;
+    }
+
+    let previous_position = playback.position;
+    playback.position += time.delta_seconds() * playback.speed;
+
+    for (board, saved_replay) in playback.boards.iter() {
+        let replay = &saved_replay.replay;
+        let steps = replay.steps_until(previous_position)..replay.steps_until(playback.position);
+        for step in &replay.steps[steps] {
+            match step.input {
+                ReplayInput::Trigger(coordinates) => {
+                    tile_trigger_event_writer.send(TileTriggerEvent {
+                        board: *board,
+                        coordinates,
+                    });
+                }
+                ReplayInput::Chord(coordinates) => {
+                    tile_chord_event_writer.send(TileChordEvent {
+                        board: *board,
+                        coordinates,
+                    });
+                }
+                ReplayInput::Mark(coordinates) => {
+                    tile_mark_event_writer.send(TileMarkEvent {
+                        board: *board,
+                        coordinates,
+                    });
+                }
+                ReplayInput::Undo | ReplayInput::Redo => {
+                    undo_request_event_writer.send(UndoRequestEvent {
+                        board: *board,
+                        redo: step.input == ReplayInput::Redo,
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{events::*, resources::*};
+use bevy::prelude::*;
+
+pub fn read_tile_chord_event(
+    mut boards: Query<&mut Board>,
+    mut tile_chord_event_reader: EventReader<TileChordEvent>,
+    mut board_outcome_event_writer: EventWriter<BoardOutcomeEvent>,
+) {
+    for chord_event in tile_chord_event_reader.read() {
+        let mut board = match boards.get_mut(chord_event.board) {
+            Ok(board) if board.is_playing() => board,
+            _ => continue,
+        };
+
+        let outcomes = board.game.chord(chord_event.coordinates);
+        board.counters.clicks += 1;
+        if !outcomes.is_empty() {
+            board.counters.useful_clicks += 1;
+        }
+
+        board_outcome_event_writer.send_batch(outcomes.into_iter().map(|outcome| {
+            BoardOutcomeEvent {
+                board: chord_event.board,
+                outcome,
+            }
+        }));
+    }
+}
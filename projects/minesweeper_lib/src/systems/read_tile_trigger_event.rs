@@ -1,14 +1,30 @@
-use crate::{components::*, events::*, resources::*};
+use crate::{events::*, resources::*};
 use bevy::prelude::*;
 
 pub fn read_tile_trigger_event(
-    mut commands: Commands,
-    board: Res<Board>,
+    mut boards: Query<&mut Board>,
     mut tile_trigger_event_reader: EventReader<TileTriggerEvent>,
+    mut board_outcome_event_writer: EventWriter<BoardOutcomeEvent>,
 ) {
     for trigger_event in tile_trigger_event_reader.read() {
-        if let Some(entity) = board.tile_to_uncover(&trigger_event.0) {
-            commands.entity(*entity).insert(Uncover);
+        let mut board = match boards.get_mut(trigger_event.board) {
+            Ok(board) if board.is_playing() => board,
+            _ => continue,
+        };
+
+        // Triggering a revealed number chords it
+        let outcomes = board.game.trigger(trigger_event.coordinates);
+
+        board.counters.clicks += 1;
+        if !outcomes.is_empty() {
+            board.counters.useful_clicks += 1;
         }
+
+        board_outcome_event_writer.send_batch(outcomes.into_iter().map(|outcome| {
+            BoardOutcomeEvent {
+                board: trigger_event.board,
+                outcome,
+            }
+        }));
     }
 }
@@ -0,0 +1,59 @@
+use crate::{events::*, resources::*, util::*};
+use bevy::prelude::*;
+
+/// Starts a replay for every new board and records the tile events sent to it, in the order they
+/// are applied
+#[allow(clippy::too_many_arguments)]
+pub fn record_replay(
+    new_boards: Query<(Entity, &Board), Added<Board>>,
+    boards: Query<(&Board, &BoardOptions)>,
+    time: Res<Time<Fixed>>,
+    mut recorder: ResMut<ReplayRecorder>,
+    mut tile_trigger_event_reader: EventReader<TileTriggerEvent>,
+    mut tile_chord_event_reader: EventReader<TileChordEvent>,
+    mut tile_mark_event_reader: EventReader<TileMarkEvent>,
+    mut undo_request_event_reader: EventReader<UndoRequestEvent>,
+) {
+    let now = time.elapsed_seconds();
+    for (entity, board) in new_boards.iter() {
+        recorder
+            .recordings
+            .insert(entity, (now, Replay::new(&board.game)));
+    }
+
+    let inputs = tile_trigger_event_reader
+        .read()
+        .map(|event| (event.board, ReplayInput::Trigger(event.coordinates)))
+        .chain(
+            tile_chord_event_reader
+                .read()
+                .map(|event| (event.board, ReplayInput::Chord(event.coordinates))),
+        )
+        .chain(
+            tile_mark_event_reader
+                .read()
+                .map(|event| (event.board, ReplayInput::Mark(event.coordinates))),
+        )
+        .chain(
+            // Only the requests `undo_actions` accepts change the game
+            undo_request_event_reader
+                .read()
+                .filter(|event| {
+                    boards.get(event.board).is_ok_and(|(board, board_options)| {
+                        board.is_playing() || (!event.redo && board_options.practice)
+                    })
+                })
+                .map(|event| {
+                    let input = match event.redo {
+                        true => ReplayInput::Redo,
+                        false => ReplayInput::Undo,
+                    };
+                    (event.board, input)
+                }),
+        );
+    for (board, input) in inputs {
+        if let Some((started_at, replay)) = recorder.recordings.get_mut(&board) {
+            replay.record(now - *started_at, input);
+        }
+    }
+}
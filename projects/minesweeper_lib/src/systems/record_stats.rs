@@ -0,0 +1,46 @@
+use crate::{components::*, events::*, resources::*, util::*};
+use bevy::prelude::*;
+
+/// Counts the finished games in the statistics, saving the statistics. The boards played back by
+/// a replay are never counted, and a game taken back by an undo is counted once.
+pub fn record_stats(
+    mut commands: Commands,
+    boards: Query<(Entity, &Board, &BoardOptions, Has<StatsRecorded>)>,
+    playback: Option<Res<ReplayPlayback>>,
+    mut game_stats: ResMut<GameStats>,
+    mut game_finished_event_reader: EventReader<GameFinishedEvent>,
+) {
+    if playback.is_some_and(|playback| playback.is_active()) {
+        for (entity, _, _, stats_recorded) in boards.iter() {
+            if !stats_recorded {
+                commands.entity(entity).insert(StatsRecorded);
+            }
+        }
+        game_finished_event_reader.clear();
+        return;
+    }
+
+    let mut recorded = false;
+    for event in game_finished_event_reader.read() {
+        let (_, board, board_options, stats_recorded) = match boards.get(event.board) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+        if stats_recorded {
+            continue;
+        }
+        commands.entity(event.board).insert(StatsRecorded);
+
+        let stats = game_stats.category_mut(StatsCategory::of(board, board_options));
+        if event.summary.won {
+            stats.record_win(event.summary.time, event.summary.analysis.three_bv);
+        } else {
+            stats.record_loss();
+        }
+        recorded = true;
+    }
+
+    if recorded {
+        game_stats.save();
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{resources::*, util::*};
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    log,
+    prelude::*,
+};
+
+/// The seconds skipped by scrubbing the playback
+const SCRUB_STEP: f32 = 5.;
+
+/// Plays back the last replay with `v`. While playing back, `space` pauses, `1`, `2` and `4` set
+/// the speed, the left and right arrows scrub and `escape` hands the boards back to the player.
+pub fn replay_input(
+    mut playback: ResMut<ReplayPlayback>,
+    mut key_event_reader: EventReader<KeyboardInput>,
+) {
+    for event in key_event_reader.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Character(character) if character.as_str() == "v" => {
+                let loaded = read_save(SAVED_REPLAYS_NAME)
+                    .and_then(|ron| SavedReplays::from_ron(&ron))
+                    .and_then(|saved_replays| {
+                        saved_replays.verify()?;
+                        Ok(saved_replays)
+                    });
+                match loaded {
+                    Ok(saved_replays) => {
+                        log::info!("Playing back {} boards", saved_replays.replays.len());
+                        *playback = ReplayPlayback {
+                            boards: saved_replays
+                                .replays
+                                .into_iter()
+                                .map(|saved_replay| (Entity::PLACEHOLDER, saved_replay))
+                                .collect(),
+                            ..default()
+                        };
+                        playback.seek(0.);
+                    }
+                    Err(error) => log::error!("Could not play back the replay: {}", error),
+                }
+            }
+            _ if !playback.is_active() => {}
+            Key::Space => playback.paused = !playback.paused,
+            Key::Character(character) => {
+                if let Ok(speed) = character.parse::<u8>() {
+                    if [1, 2, 4].contains(&speed) {
+                        playback.speed = speed as f32;
+                    }
+                }
+            }
+            Key::ArrowLeft => {
+                let position = playback.position - SCRUB_STEP;
+                playback.seek(position);
+            }
+            Key::ArrowRight => {
+                let position = playback.position + SCRUB_STEP;
+                playback.seek(position);
+            }
+            Key::Escape => {
+                log::info!("Stopped the playback");
+                *playback = default();
+            }
+            _ => {}
+        }
+    }
+}
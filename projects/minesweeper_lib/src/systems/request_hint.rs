@@ -0,0 +1,102 @@
+use crate::{components::*, events::*, resources::*, solver::*, util::*};
+use bevy::{log, prelude::*};
+
+#[allow(clippy::too_many_arguments)]
+pub fn request_hint(
+    mut commands: Commands,
+    mut boards: Query<&mut Board>,
+    board_assets: Res<BoardAssets>,
+    hints: Query<Entity, With<Hint>>,
+    parents: Query<&Parent>,
+    mut hint_request_event_reader: EventReader<HintRequestEvent>,
+    mut hint_result_event_writer: EventWriter<HintResultEvent>,
+    mut tile_trigger_event_writer: EventWriter<TileTriggerEvent>,
+) {
+    for event in hint_request_event_reader.read() {
+        let mut board = match boards.get_mut(event.board) {
+            Ok(board) if board.is_playing() => board,
+            _ => continue,
+        };
+
+        // Only the previous hint of the same board is replaced
+        for hint in hints.iter() {
+            if parents
+                .iter_ancestors(hint)
+                .any(|ancestor| ancestor == event.board)
+            {
+                commands.entity(hint).despawn_recursive();
+            }
+        }
+
+        if board.game.tile_map().max_tile_bombs() > 1 {
+            log::info!("Hints need tiles holding a single bomb");
+            continue;
+        }
+
+        let safe_tile = match board.game.pending_layout() {
+            // Bombs are placed around the first trigger, any tile is safe
+            Some(_) => board.game.tile_map().grid().center_tile(),
+            // Flags are ignored so that a wrong flag can not produce a wrong hint
+            None => deduce(&board.visible_board_ignoring_flags())
+                .safe
+                .into_iter()
+                .find(|coordinates| {
+                    board.game.tile_state(*coordinates) == Some(TileState::Covered)
+                }),
+        };
+
+        // A hint which highlights no cover is not counted
+        let hint = safe_tile.and_then(|coordinates| {
+            board
+                .covered_tiles
+                .get(&coordinates)
+                .map(|cover| (coordinates, *cover))
+        });
+        let (coordinates, cover) = match hint {
+            None => {
+                log::info!("No tile is provably safe, a guess is required");
+                hint_result_event_writer.send(HintResultEvent {
+                    board: event.board,
+                    coordinates: None,
+                });
+                continue;
+            }
+            Some(hint) => hint,
+        };
+
+        board.counters.hints_used += 1;
+        log::info!(
+            "Hint {}: {} is safe",
+            board.counters.hints_used,
+            coordinates
+        );
+
+        let size = board.tile_size - board.tile_padding;
+        commands.entity(cover).with_children(|parent| {
+            parent
+                .spawn(SpriteBundle {
+                    sprite: Sprite {
+                        color: board_assets.pending_tile_material.color,
+                        custom_size: Some(Vec2::splat(size)),
+                        ..default()
+                    },
+                    texture: board_assets.pending_tile_material.texture.clone(),
+                    transform: Transform::from_xyz(0., 0., 0.5),
+                    ..default()
+                })
+                .insert(Name::new("Hint"))
+                .insert(Hint);
+        });
+
+        hint_result_event_writer.send(HintResultEvent {
+            board: event.board,
+            coordinates: Some(coordinates),
+        });
+        if event.uncover {
+            tile_trigger_event_writer.send(TileTriggerEvent {
+                board: event.board,
+                coordinates,
+            });
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{components::*, resources::*};
+use bevy::{log, prelude::*};
+
+/// Requests a restart when the HUD reset button is pressed
+pub fn reset_button_input(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<HudResetButton>)>,
+    mut pending_restart: ResMut<PendingRestart>,
+) {
+    for interaction in interactions.iter() {
+        if *interaction == Interaction::Pressed {
+            log::info!("Restarting game");
+            pending_restart.requested = true;
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{components::*, resources::*};
+use bevy::{log, prelude::*};
+
+/// Resets the statistics when the statistics screen reset button is pressed
+pub fn reset_stats_button_input(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<StatsResetButton>)>,
+    mut game_stats: ResMut<GameStats>,
+) {
+    for interaction in interactions.iter() {
+        if *interaction == Interaction::Pressed {
+            log::info!("Resetting the statistics");
+            *game_stats = default();
+            game_stats.save();
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::resources::*;
+use bevy::prelude::*;
+
+/// Restores the time of a resumed game once its boards are spawned
+pub fn resume_game_timer(
+    mut pending_resume: ResMut<PendingResume>,
+    mut game_timer: ResMut<GameTimer>,
+) {
+    if pending_resume.is_pending() {
+        game_timer.elapsed = pending_resume.elapsed;
+        *pending_resume = default();
+    }
+}
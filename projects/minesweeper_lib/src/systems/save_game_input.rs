@@ -0,0 +1,43 @@
+use crate::{resources::*, util::*};
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    log,
+    prelude::*,
+};
+
+/// Saves the boards with `s` while one of them is being played
+pub fn save_game_input(
+    boards: Query<(&Board, &BoardOptions)>,
+    game_timer: Res<GameTimer>,
+    key_bindings: Res<KeyBindings>,
+    keyboard_cursor: Res<KeyboardCursor>,
+    mut key_event_reader: EventReader<KeyboardInput>,
+) {
+    for event in key_event_reader.read() {
+        match &event.logical_key {
+            Key::Character(character) if character.as_str() == "s" => {}
+            _ => continue,
+        }
+        if !event.state.is_pressed() || keyboard_cursor.captures(&key_bindings, event) {
+            continue;
+        }
+
+        if !boards.iter().any(|(board, _)| board.is_playing()) {
+            log::info!("No game in progress to save");
+            continue;
+        }
+
+        let saved_boards = boards
+            .iter()
+            .map(|(board, board_options)| SavedBoard::new(board, board_options))
+            .collect();
+        let saved_game = SavedGame::new(saved_boards, game_timer.elapsed);
+        match saved_game
+            .to_ron()
+            .and_then(|ron| write_save(SAVED_GAME_NAME, &ron))
+        {
+            Ok(()) => log::info!("Game saved"),
+            Err(error) => log::error!("Could not save the game: {}", error),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{resources::*, util::*};
+use bevy::{log, prelude::*};
+
+/// Saves the replays of the boards once the game is over. The recordings are kept, a game taken
+/// back by an undo keeps being recorded.
+pub fn save_replays(boards: Query<(Entity, &Board, &BoardOptions)>, recorder: Res<ReplayRecorder>) {
+    let mut replays = Vec::new();
+    for (entity, board, board_options) in boards.iter() {
+        if let Some((_, replay)) = recorder.recordings.get(&entity) {
+            let mut replay = replay.clone();
+            replay.finish(&board.game);
+            replays.push(SavedReplay {
+                options: board_options.clone(),
+                replay,
+            });
+        }
+    }
+    if replays.is_empty() {
+        return;
+    }
+
+    match SavedReplays::new(replays)
+        .to_ron()
+        .and_then(|ron| write_save(SAVED_REPLAYS_NAME, &ron))
+    {
+        Ok(()) => log::info!("Replay saved"),
+        Err(error) => log::error!("Could not save the replay: {}", error),
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{components::*, resources::*, util::*};
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+
+/// Opens and closes the statistics screen with `t`
+pub fn stats_screen_input(
+    mut commands: Commands,
+    stats_screens: Query<Entity, With<StatsScreen>>,
+    board_options: Res<BoardOptions>,
+    board_assets: Res<BoardAssets>,
+    mut key_event_reader: EventReader<KeyboardInput>,
+) {
+    for event in key_event_reader.read() {
+        match &event.logical_key {
+            Key::Character(character) if character.as_str() == "t" => {}
+            _ => continue,
+        }
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        if stats_screens.is_empty() {
+            spawn_stats_screen(&mut commands, &board_options.colors, &board_assets);
+        } else {
+            for stats_screen in stats_screens.iter() {
+                commands.entity(stats_screen).despawn_recursive();
+            }
+        }
+    }
+}
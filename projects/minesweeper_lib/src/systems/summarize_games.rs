@@ -0,0 +1,39 @@
+use crate::{components::*, events::*, resources::*, util::*};
+use bevy::prelude::*;
+
+/// Adds a summary to the boards whose game is over and sends it in a `GameFinishedEvent`. The
+/// summary is removed when an undo takes the game back.
+pub fn summarize_games(
+    mut commands: Commands,
+    boards: Query<(Entity, &Board, Has<GameSummary>)>,
+    game_timer: Option<Res<GameTimer>>,
+    mut game_finished_event_writer: EventWriter<GameFinishedEvent>,
+) {
+    for (entity, board, summarized) in boards.iter() {
+        let status = board.game.status();
+        if status == GameStatus::Playing {
+            if summarized {
+                commands.entity(entity).remove::<GameSummary>();
+            }
+            continue;
+        }
+        if summarized {
+            continue;
+        }
+
+        let summary = GameSummary {
+            won: status == GameStatus::Won,
+            time: game_timer
+                .as_ref()
+                .map_or(0., |game_timer| game_timer.elapsed),
+            analysis: board.game.tile_map().analyze(),
+            clicks: board.counters.clicks,
+            useful_clicks: board.counters.useful_clicks,
+        };
+        commands.entity(entity).insert(summary);
+        game_finished_event_writer.send(GameFinishedEvent {
+            board: entity,
+            summary,
+        });
+    }
+}
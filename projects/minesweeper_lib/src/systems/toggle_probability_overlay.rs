@@ -0,0 +1,20 @@
+use crate::resources::*;
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    log,
+    prelude::*,
+};
+
+pub fn toggle_probability_overlay(
+    mut overlay: ResMut<ProbabilityOverlay>,
+    mut key_event_reader: EventReader<KeyboardInput>,
+) {
+    for event in key_event_reader.read() {
+        if let Key::Character(character) = &event.logical_key {
+            if character.as_str() == "p" && event.state.is_pressed() {
+                overlay.enabled = !overlay.enabled;
+                log::info!("Probability overlay enabled: {}", overlay.enabled);
+            }
+        }
+    }
+}
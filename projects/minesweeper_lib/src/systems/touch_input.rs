@@ -66,9 +66,8 @@ impl TouchInputDeps<MouseButtonInput> for MouseButtonInputTouchInputDeps {
 pub fn touch_input<E: Event, D: TouchInputDeps<E>>(
     mut commands: Commands,
     windows: Query<&Window>,
-    mut touch_interpreters: Query<&mut TouchInterpretationComponent>,
+    mut boards: Query<(Entity, &Board, &mut TouchInterpretationComponent)>,
     mut cover_transforms: Query<&mut Transform, With<Cover>>,
-    board: Res<Board>,
     board_assets: Res<BoardAssets>,
     time: Res<Time<Fixed>>,
     mut touch_event_reader: EventReader<E>,
@@ -76,20 +75,28 @@ pub fn touch_input<E: Event, D: TouchInputDeps<E>>(
     mut tile_mark_event_writer: EventWriter<TileMarkEvent>,
 ) {
     let window = windows.get_single().unwrap();
-    let mut touch_interpreter = touch_interpreters.get_single_mut().unwrap();
 
     let window_position = window.cursor_position();
-    let tile_size = board.tile_size;
-    let tile_padding = board.tile_padding;
 
     for event in touch_event_reader.read() {
         let touch_state = D::get_touch_state(event);
 
         let event_position = D::get_position_from_event(event);
         let position = event_position.or(window_position);
-        let tile_coordinates = position.and_then(|position| board.mouse_position(window, position));
 
-        if let Some(tile_coordinates) = tile_coordinates {
+        // Every board interprets the touches on its own tiles
+        for (board_entity, board, mut touch_interpreter) in boards.iter_mut() {
+            if !board.is_playing() {
+                continue;
+            }
+
+            let tile_coordinates =
+                position.and_then(|position| board.mouse_position(window, position));
+            let tile_coordinates = match tile_coordinates {
+                None => continue,
+                Some(tile_coordinates) => tile_coordinates,
+            };
+
             // Only highlight the tile if it is covered
             let z = if board.covered_tiles.contains_key(&tile_coordinates) {
                 3.5
@@ -101,25 +108,21 @@ pub fn touch_input<E: Event, D: TouchInputDeps<E>>(
                 TouchState::Started => {
                     log::trace!("Touch started: {}", tile_coordinates);
 
+                    // A new touch replaces the pending one
+                    if let Some(touch_interpretation_data) = touch_interpreter.data.take() {
+                        commands
+                            .entity(touch_interpretation_data.cover_entity)
+                            .despawn_recursive();
+                    }
+
                     // Create an entity to highlight the tile
-                    let cover_entity = commands
-                        .spawn(SpriteBundle {
-                            sprite: Sprite {
-                                color: board_assets.pending_tile_material.color,
-                                custom_size: Some(Vec2::splat(tile_size - tile_padding)),
-                                ..default()
-                            },
-                            texture: board_assets.pending_tile_material.texture.clone(),
-                            transform: Transform::from_xyz(
-                                (tile_coordinates.x as f32 * tile_size) + (tile_size / 2.),
-                                (tile_coordinates.y as f32 * tile_size) + (tile_size / 2.),
-                                z,
-                            ),
-                            ..default()
-                        })
-                        .insert(Cover)
-                        .id();
-                    commands.entity(board.entity).push_children(&[cover_entity]);
+                    let cover_entity = spawn_pending_tile(
+                        &mut commands,
+                        board,
+                        &board_assets,
+                        tile_coordinates,
+                        z,
+                    );
                     touch_interpreter.data = Some(TouchInterpretationData {
                         x: tile_coordinates.x,
                         y: tile_coordinates.y,
@@ -137,19 +140,21 @@ pub fn touch_input<E: Event, D: TouchInputDeps<E>>(
                         let timespan = timestamp - touch_interpretation_data.timestamp;
                         if timespan < 0.5 {
                             log::info!("Trying to uncover tile on {}", tile_coordinates);
-                            tile_trigger_event_writer.send(TileTriggerEvent(tile_coordinates));
+                            tile_trigger_event_writer.send(TileTriggerEvent {
+                                board: board_entity,
+                                coordinates: tile_coordinates,
+                            });
                         } else {
                             let tile_coordinates = Coordinates {
                                 x: touch_interpretation_data.x,
                                 y: touch_interpretation_data.y,
                             };
 
-                            commands
-                                .entity(touch_interpretation_data.cover_entity)
-                                .despawn_recursive();
-
                             log::info!("Trying to mark tile on {}", tile_coordinates);
-                            tile_mark_event_writer.send(TileMarkEvent(tile_coordinates));
+                            tile_mark_event_writer.send(TileMarkEvent {
+                                board: board_entity,
+                                coordinates: tile_coordinates,
+                            });
                         }
 
                         // Destroy the entity which covers the tile
@@ -159,57 +164,70 @@ pub fn touch_input<E: Event, D: TouchInputDeps<E>>(
                     }
                 }
                 TouchState::Other => {
-                    if let Some(ref mut touch_interpretation_data) = &mut touch_interpreter.data {
-                        if touch_interpretation_data.x != tile_coordinates.x
-                            || touch_interpretation_data.y != tile_coordinates.y
-                        {
-                            touch_interpretation_data.x = tile_coordinates.x;
-                            touch_interpretation_data.y = tile_coordinates.y;
-                            touch_interpretation_data.timestamp = time.elapsed_seconds();
-                        }
-                    }
-
-                    for mut cover_transform in cover_transforms.iter_mut() {
-                        cover_transform.translation = Vec3::new(
-                            (tile_coordinates.x as f32 * tile_size) + (tile_size / 2.),
-                            (tile_coordinates.y as f32 * tile_size) + (tile_size / 2.),
-                            z,
-                        );
-                    }
+                    move_pending_tile(
+                        &mut touch_interpreter,
+                        &mut cover_transforms,
+                        board,
+                        tile_coordinates,
+                        z,
+                        time.elapsed_seconds(),
+                    );
                 }
             }
         }
     }
 
     if D::auto_update() {
-        let tile_coordinates =
-            window_position.and_then(|position| board.mouse_position(window, position));
-
-        if let Some(tile_coordinates) = tile_coordinates {
-            if let Some(ref mut touch_interpretation_data) = &mut touch_interpreter.data {
-                if touch_interpretation_data.x != tile_coordinates.x
-                    || touch_interpretation_data.y != tile_coordinates.y
-                {
-                    touch_interpretation_data.x = tile_coordinates.x;
-                    touch_interpretation_data.y = tile_coordinates.y;
-                    touch_interpretation_data.timestamp = time.elapsed_seconds();
-                }
-            }
-
-            // Only highlight the tile if it is covered
-            let z = if board.covered_tiles.contains_key(&tile_coordinates) {
-                3.5
-            } else {
-                0.
-            };
-
-            for mut cover_transform in cover_transforms.iter_mut() {
-                cover_transform.translation = Vec3::new(
-                    (tile_coordinates.x as f32 * tile_size) + (tile_size / 2.),
-                    (tile_coordinates.y as f32 * tile_size) + (tile_size / 2.),
+        for (_, board, mut touch_interpreter) in boards.iter_mut() {
+            let tile_coordinates =
+                window_position.and_then(|position| board.mouse_position(window, position));
+
+            if let Some(tile_coordinates) = tile_coordinates {
+                // Only highlight the tile if it is covered
+                let z = if board.covered_tiles.contains_key(&tile_coordinates) {
+                    3.5
+                } else {
+                    0.
+                };
+
+                move_pending_tile(
+                    &mut touch_interpreter,
+                    &mut cover_transforms,
+                    board,
+                    tile_coordinates,
                     z,
+                    time.elapsed_seconds(),
                 );
             }
         }
     }
 }
+
+/// Moves the pending touch of a board and its highlight to the tile at `tile_coordinates`
+fn move_pending_tile(
+    touch_interpreter: &mut TouchInterpretationComponent,
+    cover_transforms: &mut Query<&mut Transform, With<Cover>>,
+    board: &Board,
+    tile_coordinates: Coordinates,
+    z: f32,
+    timestamp: f32,
+) {
+    let touch_interpretation_data = match &mut touch_interpreter.data {
+        None => return,
+        Some(touch_interpretation_data) => touch_interpretation_data,
+    };
+
+    if touch_interpretation_data.x != tile_coordinates.x
+        || touch_interpretation_data.y != tile_coordinates.y
+    {
+        touch_interpretation_data.x = tile_coordinates.x;
+        touch_interpretation_data.y = tile_coordinates.y;
+        touch_interpretation_data.timestamp = timestamp;
+    }
+
+    if let Ok(mut cover_transform) =
+        cover_transforms.get_mut(touch_interpretation_data.cover_entity)
+    {
+        cover_transform.translation = pending_tile_translation(board, tile_coordinates, z);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{events::*, resources::*};
+use bevy::{log, prelude::*};
+
+/// Undoes and redoes the actions of the boards. An action which ended the game can only be undone
+/// in practice mode.
+pub fn undo_actions(
+    mut boards: Query<(&mut Board, &BoardOptions)>,
+    mut undo_request_event_reader: EventReader<UndoRequestEvent>,
+    mut board_outcome_event_writer: EventWriter<BoardOutcomeEvent>,
+) {
+    for event in undo_request_event_reader.read() {
+        let (mut board, board_options) = match boards.get_mut(event.board) {
+            Ok(v) => v,
+            Err(e) => {
+                log::error!("{}", e);
+                continue;
+            }
+        };
+
+        let outcomes = if event.redo {
+            if !board.is_playing() {
+                continue;
+            }
+            board.game.redo()
+        } else {
+            if !board.is_playing() && !board_options.practice {
+                log::info!("The game is over, undoing it needs the practice mode");
+                continue;
+            }
+            board.game.undo()
+        };
+
+        board_outcome_event_writer.send_batch(outcomes.into_iter().map(|outcome| {
+            BoardOutcomeEvent {
+                board: event.board,
+                outcome,
+            }
+        }));
+    }
+}
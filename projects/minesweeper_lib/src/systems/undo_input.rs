@@ -0,0 +1,48 @@
+use crate::{events::*, resources::*};
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+
+/// Undoes with `ctrl + z` and redoes with `ctrl + y` on the board under the cursor, or on the only
+/// board
+pub fn undo_input(
+    windows: Query<&Window>,
+    boards: Query<(Entity, &Board)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut key_event_reader: EventReader<KeyboardInput>,
+    mut undo_request_event_writer: EventWriter<UndoRequestEvent>,
+) {
+    let window = windows.get_single().unwrap();
+
+    for event in key_event_reader.read() {
+        if !event.state.is_pressed()
+            || !keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+        {
+            continue;
+        }
+
+        let redo = match &event.logical_key {
+            Key::Character(character) if character.as_str() == "z" => false,
+            Key::Character(character) if character.as_str() == "y" => true,
+            _ => continue,
+        };
+
+        let hovered_board = window.cursor_position().and_then(|cursor_position| {
+            boards.iter().find_map(|(board_entity, board)| {
+                board
+                    .mouse_position(window, cursor_position)
+                    .map(|_| board_entity)
+            })
+        });
+        let board = match hovered_board {
+            Some(board) => board,
+            None => match boards.get_single() {
+                Ok((board, _)) => board,
+                Err(_) => continue,
+            },
+        };
+
+        undo_request_event_writer.send(UndoRequestEvent { board, redo });
+    }
+}
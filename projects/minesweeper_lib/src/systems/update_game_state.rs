@@ -0,0 +1,33 @@
+use crate::{resources::*, util::*};
+use bevy::{log, prelude::*};
+
+/// Ends the game once every board is over, the game is won when any board was won
+pub fn update_game_state(
+    boards: Query<&Board>,
+    game_state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    // An undo in practice mode takes a lost game back
+    if *game_state.get() != GameState::Playing {
+        if boards.iter().any(|board| board.is_playing()) {
+            log::info!("Game resumed");
+            next_state.set(GameState::Playing);
+        }
+        return;
+    }
+
+    if boards.is_empty() || boards.iter().any(|board| board.is_playing()) {
+        return;
+    }
+
+    let game_state = if boards
+        .iter()
+        .any(|board| board.game.status() == GameStatus::Won)
+    {
+        GameState::Won
+    } else {
+        GameState::Lost
+    };
+    log::info!("Game over: {:?}", game_state);
+    next_state.set(game_state);
+}
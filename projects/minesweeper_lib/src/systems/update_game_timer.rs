@@ -0,0 +1,32 @@
+use crate::{events::*, resources::*, util::*};
+use bevy::prelude::*;
+
+/// Starts the timer on the first tile revealed or layout placed on any board, not on the tiles a
+/// safe start reveals before the board is played, and stops it when the game is over. A resumed
+/// game which was already timed keeps counting.
+pub fn update_game_timer(
+    game_state: Res<State<GameState>>,
+    time: Res<Time>,
+    mut game_timer: ResMut<GameTimer>,
+    mut board_outcome_event_reader: EventReader<BoardOutcomeEvent>,
+) {
+    let started = board_outcome_event_reader.read().any(|event| {
+        matches!(
+            event.outcome,
+            Outcome::Revealed { .. } | Outcome::LaidOut { .. }
+        )
+    });
+
+    if *game_state.get() != GameState::Playing {
+        game_timer.running = false;
+        return;
+    }
+
+    if !game_timer.running && (started || game_timer.elapsed > 0.) {
+        game_timer.running = true;
+    }
+
+    if game_timer.running {
+        game_timer.elapsed += time.delta_seconds();
+    }
+}
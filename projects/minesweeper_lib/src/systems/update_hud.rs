@@ -0,0 +1,39 @@
+use crate::{components::*, resources::*, util::*};
+use bevy::prelude::*;
+
+/// Refreshes the mine counter, the timer and the face of the reset button. The mine counter adds
+/// up the unmarked bombs of every board.
+pub fn update_hud(
+    boards: Query<&Board>,
+    game_timer: Res<GameTimer>,
+    game_state: Res<State<GameState>>,
+    mut mine_counters: Query<&mut Text, (With<HudMineCounter>, Without<HudTimer>)>,
+    mut timers: Query<&mut Text, (With<HudTimer>, Without<HudMineCounter>)>,
+    reset_buttons: Query<&Children, With<HudResetButton>>,
+    mut texts: Query<&mut Text, (Without<HudMineCounter>, Without<HudTimer>)>,
+) {
+    let remaining_mines: i32 = boards
+        .iter()
+        .map(|board| board.game.bomb_count() as i32 - board.game.flagged_count() as i32)
+        .sum();
+    for mut text in mine_counters.iter_mut() {
+        text.sections[0].value = format!("{:03}", remaining_mines);
+    }
+
+    for mut text in timers.iter_mut() {
+        text.sections[0].value = format!("{:03}", game_timer.elapsed as u32);
+    }
+
+    let face = match game_state.get() {
+        GameState::Playing => ":)",
+        GameState::Won => "B)",
+        GameState::Lost => "X(",
+    };
+    for children in reset_buttons.iter() {
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(*child) {
+                text.sections[0].value = face.to_string();
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{resources::*, solver::*};
+use bevy::{color::Mix, log, prelude::*};
+
+/// Tints the tile covers of every board by their probability of holding a mine, recomputing only
+/// when the covered or marked tiles of a board change. The tiles whose mines are too entangled to
+/// be counted are half highlighted as unknown, and no other tile is tinted since their
+/// probabilities depend on them.
+pub fn update_probability_overlay(
+    mut boards: Query<(Entity, &mut Board)>,
+    board_assets: Res<BoardAssets>,
+    mut overlay: ResMut<ProbabilityOverlay>,
+    mut sprites: Query<&mut Sprite>,
+) {
+    for (board_entity, mut board) in boards.iter_mut() {
+        if !overlay.enabled {
+            if overlay
+                .tinted_covered_tile_counts
+                .remove(&board_entity)
+                .is_some()
+            {
+                overlay.tinted_marked_tiles.remove(&board_entity);
+                for entity in board.covered_tiles.values() {
+                    if let Ok(mut sprite) = sprites.get_mut(*entity) {
+                        sprite.color = board_assets.covered_tile_material.color;
+                    }
+                }
+            }
+            continue;
+        }
+
+        // The probabilities are only computed for tiles holding a single bomb
+        if board.game.tile_map().max_tile_bombs() > 1 {
+            continue;
+        }
+
+        if overlay.tinted_covered_tile_counts.get(&board_entity) == Some(&board.covered_tiles.len())
+            && overlay
+                .tinted_marked_tiles
+                .get(&board_entity)
+                .is_some_and(|marked_tiles| {
+                    marked_tiles.iter().copied().eq(board.game.iter_flagged())
+                })
+        {
+            continue;
+        }
+        overlay
+            .tinted_covered_tile_counts
+            .insert(board_entity, board.covered_tiles.len());
+        overlay
+            .tinted_marked_tiles
+            .insert(board_entity, board.game.iter_flagged().collect());
+
+        // Bombs are not placed yet on a pending layout, so there is nothing to show
+        let probabilities = match board.game.pending_layout() {
+            None => mine_probabilities(&board.visible_board()),
+            Some(_) => Ok(default()),
+        };
+        // A game played with the probabilities in sight is assisted
+        if probabilities
+            .as_ref()
+            .is_ok_and(|probabilities| !probabilities.is_empty())
+            && !board.counters.probabilities_shown
+        {
+            board.counters.probabilities_shown = true;
+        }
+        if let Err(ProbabilityError::TooComplex(tiles)) = &probabilities {
+            log::warn!(
+                "The probabilities of board {:?} are unknown, {} tiles are too entangled",
+                board_entity,
+                tiles.len()
+            );
+        }
+
+        for (coordinates, entity) in board.covered_tiles.iter() {
+            let covered_color = board_assets.covered_tile_material.color;
+            let color = match &probabilities {
+                Ok(probabilities) => match probabilities.get(coordinates) {
+                    None => covered_color,
+                    Some(probability) => {
+                        covered_color.mix(&board_assets.bomb_material.color, *probability as f32)
+                    }
+                },
+                Err(ProbabilityError::TooComplex(tiles)) if tiles.contains(coordinates) => {
+                    covered_color.mix(&board_assets.pending_tile_material.color, 0.5)
+                }
+                Err(_) => covered_color,
+            };
+
+            if let Ok(mut sprite) = sprites.get_mut(*entity) {
+                sprite.color = color;
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{components::*, resources::*};
+use bevy::prelude::*;
+
+/// Lists the statistics of every category on the statistics screen
+pub fn update_stats_screen(
+    game_stats: Res<GameStats>,
+    mut texts: Query<&mut Text, With<StatsScreenText>>,
+    added_texts: Query<(), Added<StatsScreenText>>,
+) {
+    if !game_stats.is_changed() && added_texts.is_empty() {
+        return;
+    }
+
+    let lines: Vec<String> = game_stats
+        .categories
+        .iter()
+        .map(|(category, stats)| {
+            let win_rate = stats.win_rate().unwrap_or_default() * 100.;
+            let best_time = stats
+                .best_time
+                .map_or("-".to_string(), |best_time| format!("{:.1}s", best_time));
+            let three_bv_per_second = stats
+                .average_three_bv_per_second()
+                .map_or("-".to_string(), |v| format!("{:.2}", v));
+            format!(
+                "{}: {} played, {} won ({:.0}%), streak {} (best {}), best time {}, 3BV/s {}",
+                category,
+                stats.played,
+                stats.won,
+                win_rate,
+                stats.streak,
+                stats.best_streak,
+                best_time,
+                three_bv_per_second
+            )
+        })
+        .collect();
+    let value = if lines.is_empty() {
+        "No game played yet".to_string()
+    } else {
+        lines.join("\n")
+    };
+
+    for mut text in texts.iter_mut() {
+        text.sections[0].value = value.clone();
+    }
+}
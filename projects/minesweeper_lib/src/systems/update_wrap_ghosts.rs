@@ -0,0 +1,84 @@
+use crate::{components::*, resources::*, util::*};
+use bevy::prelude::*;
+
+/// How much of a wrap ghost the fog over it hides
+const FOG_ALPHA: f32 = 0.6;
+
+/// Places the wrap ghosts beyond the edges of their board and draws them again when the state of
+/// the tile they copy changes. A ghost of a bomb is revealed once the game is lost.
+pub fn update_wrap_ghosts(
+    mut commands: Commands,
+    boards: Query<&Board>,
+    mut ghosts: Query<(Entity, &mut WrapGhost, &mut Transform, &mut Sprite)>,
+    board_assets: Res<BoardAssets>,
+) {
+    for (entity, mut ghost, mut transform, mut sprite) in ghosts.iter_mut() {
+        let Ok(board) = boards.get(ghost.board) else {
+            continue;
+        };
+
+        let period = board.topology().tile_step() * board.map_size().as_vec2() * board.tile_size;
+        transform.translation =
+            (board.tile_translation(ghost.coordinates) + ghost.shift * period).extend(1.);
+
+        let game = &board.game;
+        let tile_state = match game.tile_state(ghost.coordinates) {
+            Some(TileState::Covered)
+                if game.status() == GameStatus::Lost
+                    && game.tile_map().is_bomb_at(ghost.coordinates) =>
+            {
+                TileState::Revealed
+            }
+            Some(tile_state) => tile_state,
+            None => continue,
+        };
+        let size = board.tile_size - board.tile_padding;
+        let flag_count = game.flag_count(ghost.coordinates);
+        if ghost.shown == Some((tile_state, flag_count, size)) {
+            continue;
+        }
+        ghost.shown = Some((tile_state, flag_count, size));
+
+        sprite.color = board_assets.tile_material.color;
+        sprite.custom_size = Some(Vec2::splat(size));
+        commands.entity(entity).despawn_descendants();
+        commands.entity(entity).with_children(|parent| {
+            match tile_state {
+                TileState::Covered => {
+                    parent.spawn(board_assets.cover_sprite_bundle(size));
+                }
+                TileState::Flagged => {
+                    parent
+                        .spawn(board_assets.cover_sprite_bundle(size))
+                        .with_children(|parent| {
+                            spawn_flag(parent, &board_assets, size, flag_count);
+                        });
+                }
+                TileState::Revealed => {
+                    match game.tile_map().map()[ghost.coordinates.y as usize]
+                        [ghost.coordinates.x as usize]
+                    {
+                        Tile::Bomb(count) => {
+                            spawn_bomb(parent, &board_assets, size, count);
+                        }
+                        Tile::BombNeighbor(number) => {
+                            parent.spawn(board_assets.bomb_count_text_bundle(number, size));
+                        }
+                        Tile::Empty | Tile::Void => {}
+                    }
+                }
+            }
+
+            // The fog fades the copy so that it is not mistaken for a tile of the board
+            parent.spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: board_assets.board_material.color.with_alpha(FOG_ALPHA),
+                    custom_size: Some(Vec2::splat(size)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(0., 0., 4.),
+                ..default()
+            });
+        });
+    }
+}
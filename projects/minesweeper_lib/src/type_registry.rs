@@ -10,14 +10,42 @@ impl RegisterTypes for TypeRegistry {
         app.register_type::<Bomb>();
         app.register_type::<BombNeighbor>();
         app.register_type::<Coordinates>();
+        app.register_type::<Flag>();
+        app.register_type::<GameSummary>();
+        app.register_type::<Hint>();
+        app.register_type::<Hud>();
+        app.register_type::<HudMineCounter>();
+        app.register_type::<HudResetButton>();
+        app.register_type::<HudTimer>();
+        app.register_type::<HudWinScreen>();
+        app.register_type::<StatsRecorded>();
+        app.register_type::<StatsResetButton>();
+        app.register_type::<StatsScreen>();
+        app.register_type::<StatsScreenText>();
         app.register_type::<TouchInterpretationComponent>();
         app.register_type::<Uncover>();
+        app.register_type::<WrapGhost>();
 
         // Resources
         app.register_type::<BoardOptions>();
+        app.register_type::<GameTimer>();
+        app.register_type::<KeyBindings>();
+        app.register_type::<PendingRestart>();
+        app.register_type::<ProbabilityOverlay>();
 
         // Util
+        app.register_type::<BoardCounters>();
+        app.register_type::<Game>();
+        app.register_type::<GameStatus>();
+        app.register_type::<Grid>();
+        app.register_type::<Mask>();
+        app.register_type::<Neighborhood>();
+        app.register_type::<PendingLayout>();
+        app.register_type::<TileMapAnalysis>();
         app.register_type::<TileEntities>();
+        app.register_type::<TileState>();
+        app.register_type::<Topology>();
+        app.register_type::<Wrap>();
         app.register_type::<TouchInterpretationData>();
     }
 }
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Counts what the player did on a board, kept in saves so that a resumed game is summarized and
+/// recorded like one played at once
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct BoardCounters {
+    pub hints_used: u32,
+    /// The tile events the player sent to the board
+    pub clicks: u32,
+    /// The clicks which changed the game
+    pub useful_clicks: u32,
+    /// Were the mine probabilities shown on the board
+    #[serde(default)]
+    pub probabilities_shown: bool,
+}
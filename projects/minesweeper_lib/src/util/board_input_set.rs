@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// The systems turning the player input into tile and hint events
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
+pub struct BoardInputSet;
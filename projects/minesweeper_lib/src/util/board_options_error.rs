@@ -0,0 +1,111 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Why board options can not be played
+#[derive(Clone, Debug, PartialEq)]
+pub enum BoardOptionsError {
+    /// The map has no tiles
+    EmptyMap,
+    /// The mask does not have a cell for every tile of the map
+    InvalidMask,
+    /// The bombs do not fit on the tiles without filling them all
+    TooManyBombs { bomb_count: u16, tile_count: u32 },
+    /// The bombs cover more of the map than the maximum density allows
+    TooDense {
+        bomb_count: u16,
+        max_bomb_count: u32,
+    },
+    /// The tiles kept free of bombs for the first trigger do not fit next to the bombs
+    NoRoomForStart {
+        bomb_count: u16,
+        start_tile_count: u32,
+        tile_count: u32,
+    },
+    /// Cross and knight moves are only defined on square tiles
+    SquareOnlyNeighborhood,
+    /// Custom neighbor offsets repeat or include the tile itself
+    InvalidNeighborOffsets,
+    /// A tile has no neighbors or more than a number can count
+    NeighborCount { neighbor_count: usize },
+    /// Hex rows can not alternate across the wrapped bottom and top edges of an odd number of rows
+    OddWrappedHexRows { height: u16 },
+    /// A tile can not hold a single bomb
+    NoTileBombs,
+    /// The neighbors of a tile can hold more bombs than a number can count
+    NumberOverflow { max_number: usize },
+    /// No-guess layouts are only generated for tiles holding a single bomb
+    NoGuessTileBombs,
+}
+
+impl Display for BoardOptionsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyMap => write!(f, "the map has no tiles"),
+            Self::InvalidMask => write!(f, "the mask does not have the size of the map"),
+            Self::TooManyBombs {
+                bomb_count,
+                tile_count,
+            } => write!(
+                f,
+                "{} bombs do not fit on a map of {} tiles, at least one tile must be safe",
+                bomb_count, tile_count
+            ),
+            Self::TooDense {
+                bomb_count,
+                max_bomb_count,
+            } => write!(
+                f,
+                "{} bombs are too many for the map, at most {} are allowed",
+                bomb_count, max_bomb_count
+            ),
+            Self::NoRoomForStart {
+                bomb_count,
+                start_tile_count,
+                tile_count,
+            } => write!(
+                f,
+                "{} bombs leave no room for the {} safe tiles of the start on a map of {} tiles",
+                bomb_count, start_tile_count, tile_count
+            ),
+            Self::SquareOnlyNeighborhood => {
+                write!(f, "cross and knight neighborhoods need square tiles")
+            }
+            Self::InvalidNeighborOffsets => write!(
+                f,
+                "neighbor offsets must be distinct and must not include the tile itself"
+            ),
+            Self::NeighborCount { neighbor_count } => write!(
+                f,
+                "tiles need between 1 and {} neighbors, not {}",
+                u8::MAX,
+                neighbor_count
+            ),
+            Self::OddWrappedHexRows { height } => write!(
+                f,
+                "hex maps wrapping vertically need an even number of rows, not {}",
+                height
+            ),
+            Self::NoTileBombs => write!(f, "tiles must be able to hold at least one bomb"),
+            Self::NumberOverflow { max_number } => write!(
+                f,
+                "numbers up to {} do not fit, numbers count at most {} bombs",
+                max_number,
+                u8::MAX
+            ),
+            Self::NoGuessTileBombs => {
+                write!(f, "no-guess layouts need tiles holding a single bomb")
+            }
+        }
+    }
+}
+
+impl Error for BoardOptionsError {}
+
+#[cfg(feature = "wasm")]
+impl From<BoardOptionsError> for wasm_bindgen::JsValue {
+    fn from(error: BoardOptionsError) -> Self {
+        Self::from_str(&error.to_string())
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// The statistics of the games of a category
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CategoryStats {
+    pub played: u32,
+    pub won: u32,
+
+    /// The number of games won since the last lost one
+    pub streak: u32,
+    pub best_streak: u32,
+
+    /// The shortest time a game was won in, in seconds
+    pub best_time: Option<f32>,
+
+    /// The sum of the 3BV/s of the games won
+    pub three_bv_per_second_sum: f32,
+}
+
+impl CategoryStats {
+    /// Counts a game won in `time` seconds on a map of `three_bv` 3BV
+    pub fn record_win(&mut self, time: f32, three_bv: u32) {
+        self.played += 1;
+        self.won += 1;
+        self.streak += 1;
+        self.best_streak = self.best_streak.max(self.streak);
+        self.best_time = Some(self.best_time.map_or(time, |best_time| best_time.min(time)));
+        if time > 0. {
+            self.three_bv_per_second_sum += three_bv as f32 / time;
+        }
+    }
+
+    /// Counts a lost game, which ends the streak
+    pub fn record_loss(&mut self) {
+        self.played += 1;
+        self.streak = 0;
+    }
+
+    /// Returns the share of the games played which were won, `None` before the first game
+    pub fn win_rate(&self) -> Option<f32> {
+        (self.played > 0).then(|| self.won as f32 / self.played as f32)
+    }
+
+    /// Returns the average 3BV/s of the games won, `None` before the first win
+    pub fn average_three_bv_per_second(&self) -> Option<f32> {
+        (self.won > 0).then(|| self.three_bv_per_second_sum / self.won as f32)
+    }
+}
@@ -0,0 +1,48 @@
+/// Repeats the moves of a held direction like a held key, faster the longer it is held
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CursorRepeat {
+    direction: (i32, i32),
+
+    /// Seconds before the next repeated move
+    wait: f32,
+
+    /// Seconds between the repeated moves following the next one
+    interval: f32,
+}
+
+impl CursorRepeat {
+    /// Seconds a direction is held before its moves repeat
+    const FIRST_DELAY: f32 = 0.35;
+    const FIRST_INTERVAL: f32 = 0.12;
+    const MIN_INTERVAL: f32 = 0.03;
+    /// Shortens the interval after every repeated move
+    const ACCELERATION: f32 = 0.85;
+
+    /// Starts with a direction already held, which does not move until it repeats
+    pub fn held(direction: (i32, i32)) -> Self {
+        Self {
+            direction,
+            wait: Self::FIRST_DELAY,
+            interval: Self::FIRST_INTERVAL,
+        }
+    }
+
+    /// Returns the move to make for the direction held during the last `delta` seconds, if any
+    pub fn update(&mut self, direction: (i32, i32), delta: f32) -> Option<(i32, i32)> {
+        if direction != self.direction {
+            *self = Self::held(direction);
+            return (direction != (0, 0)).then_some(direction);
+        }
+        if direction == (0, 0) {
+            return None;
+        }
+
+        self.wait -= delta;
+        if self.wait > 0. {
+            return None;
+        }
+        self.wait += self.interval;
+        self.interval = (self.interval * Self::ACCELERATION).max(Self::MIN_INTERVAL);
+        Some(direction)
+    }
+}
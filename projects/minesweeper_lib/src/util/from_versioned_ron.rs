@@ -0,0 +1,19 @@
+use crate::util::*;
+use serde::{de::DeserializeOwned, Deserialize};
+
+/// The part of a save read first to pick how to read the rest
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+/// Reads a save whose `version` field must match the version of the format this build writes
+pub fn from_versioned_ron<T: DeserializeOwned>(ron: &str, version: u32) -> Result<T, SaveError> {
+    let header: SaveHeader =
+        ron::from_str(ron).map_err(|error| SaveError::Format(error.to_string()))?;
+    if header.version != version {
+        return Err(SaveError::UnsupportedVersion(header.version));
+    }
+
+    ron::from_str(ron).map_err(|error| SaveError::Format(error.to_string()))
+}
@@ -0,0 +1,13 @@
+use crate::util::AppState;
+use bevy::prelude::*;
+
+/// The outcome of the game played while in `AppState::InGame`, `Playing` until every board is
+/// over. The game is won when any board was won.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, SubStates)]
+#[source(AppState = AppState::InGame)]
+pub enum GameState {
+    #[default]
+    Playing,
+    Won,
+    Lost,
+}
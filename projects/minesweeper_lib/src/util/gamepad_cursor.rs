@@ -0,0 +1,8 @@
+use crate::util::*;
+
+/// The tile cursor of a gamepad and the repeat of its held direction
+#[derive(Debug, Clone, Copy)]
+pub struct GamepadCursor {
+    pub cursor: TileCursor,
+    pub repeat: CursorRepeat,
+}
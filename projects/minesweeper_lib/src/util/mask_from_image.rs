@@ -0,0 +1,64 @@
+use crate::util::*;
+use bevy::{prelude::*, render::render_resource::TextureFormat};
+
+/// Creates a mask with a tile for every opaque pixel of an image, like a PNG loaded by the asset
+/// server, the top row of pixels being the top row of tiles. Pixels at least half opaque count as
+/// opaque. Returns `None` when the pixels of the image can not be read.
+pub fn mask_from_image(image: &Image) -> Option<Mask> {
+    let image = image.convert(TextureFormat::Rgba8UnormSrgb)?;
+    let size = image.size();
+    let (width, height) = (u16::try_from(size.x).ok()?, u16::try_from(size.y).ok()?);
+
+    Some(Mask::from_fn(width, height, |coordinates| {
+        let row = (height - 1 - coordinates.y) as usize;
+        let index = row * width as usize + coordinates.x as usize;
+        image.data[index * 4 + 3] >= 128
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Coordinates;
+    use bevy::render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension},
+    };
+
+    #[test]
+    fn opaque_pixels_are_tiles_from_the_top_row() {
+        // 3x2 pixels, the top row first: opaque, transparent, half opaque, then transparent,
+        // almost transparent, opaque
+        let alphas = [255, 0, 128, 0, 127, 255];
+        let data = alphas
+            .iter()
+            .flat_map(|alpha| [255, 255, 255, *alpha])
+            .collect();
+        let image = Image::new(
+            Extent3d {
+                width: 3,
+                height: 2,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        let mask = mask_from_image(&image).unwrap();
+
+        assert_eq!((mask.width(), mask.height()), (3, 2));
+        let tiles: Vec<Coordinates> = Grid::new(3, 2)
+            .iter_coordinates()
+            .filter(|coordinates| mask.contains(*coordinates))
+            .collect();
+        assert_eq!(
+            tiles,
+            [
+                Coordinates { x: 2, y: 0 },
+                Coordinates { x: 0, y: 1 },
+                Coordinates { x: 2, y: 1 }
+            ]
+        );
+    }
+}
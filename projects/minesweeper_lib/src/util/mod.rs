@@ -1,18 +1,73 @@
 mod app_state;
+mod board_counters;
+mod board_input_set;
+mod board_options_error;
 mod bounds_2;
 mod canvas_size;
+mod category_stats;
+mod cursor_repeat;
+mod from_versioned_ron;
+mod game_state;
+mod gamepad_cursor;
 mod get_canvas_size;
+mod mask_from_image;
 mod register_types;
+mod save_error;
+mod save_storage;
+mod saved_board;
+mod saved_game;
+mod saved_replay;
+mod saved_replays;
+mod saved_stats;
 mod set_canvas_size;
+mod spawn_bomb;
+mod spawn_flag;
+mod spawn_pending_tile;
+mod spawn_stats_screen;
+mod spawn_tile_kind;
+mod spawn_wrap_ghosts;
+mod stats_category;
+mod tile_cursor;
 mod tile_entities;
+mod tile_layout;
 mod touch_interpretation_data;
 
 pub use app_state::*;
+pub use board_counters::*;
+pub use board_input_set::*;
+pub use board_options_error::*;
 pub use bounds_2::*;
+pub use category_stats::*;
+pub use cursor_repeat::*;
+pub use from_versioned_ron::*;
+pub use game_state::*;
+pub use gamepad_cursor::*;
 pub use get_canvas_size::*;
+pub use mask_from_image::*;
 pub use register_types::*;
+pub use save_error::*;
+pub use save_storage::*;
+pub use saved_board::*;
+pub use saved_game::*;
+pub use saved_replay::*;
+pub use saved_replays::*;
+pub use saved_stats::*;
 pub use set_canvas_size::*;
+pub use spawn_bomb::*;
+pub use spawn_flag::*;
+pub use spawn_pending_tile::*;
+pub use spawn_stats_screen::*;
+pub use spawn_tile_kind::*;
+pub use spawn_wrap_ghosts::*;
+pub use stats_category::*;
+pub use tile_cursor::*;
 pub use tile_entities::*;
+pub use tile_layout::*;
 pub use touch_interpretation_data::*;
 
+pub use minesweeper_core::{
+    Game, GameStatus, Grid, Mask, Neighborhood, Outcome, PendingLayout, Replay, ReplayInput,
+    ReplayStep, TileMapAnalysis, TileState, Topology, Wrap,
+};
+
 use canvas_size::*;
@@ -0,0 +1,97 @@
+use crate::util::BoardOptionsError;
+use minesweeper_core::{ReplayError, SnapshotError};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Why a game could not be saved or loaded
+#[derive(Clone, Debug, PartialEq)]
+pub enum SaveError {
+    /// The platform has no place to keep saves
+    NoStorage,
+    /// No game was saved
+    NotFound,
+    /// Reading or writing the save failed
+    Storage(String),
+    /// The save is not in the save format
+    Format(String),
+    /// The save was written in a version of the format this build does not read
+    UnsupportedVersion(u32),
+    /// The map size of a saved board does not match the one of its options
+    MapSizeMismatch {
+        options: (u16, u16),
+        game: (u16, u16),
+    },
+    /// The most bombs a tile of a saved board can hold does not match the one of its options
+    MaxTileBombsMismatch { options: u8, game: u8 },
+    /// The topology, wrap, neighborhood or mask of a saved board does not match the ones of its
+    /// options
+    GridMismatch,
+    /// The options of a saved board can not be played
+    InvalidOptions(BoardOptionsError),
+    /// A saved board does not describe a game which can be restored
+    InvalidGame(SnapshotError),
+    /// A saved replay does not reproduce the game it was recorded from
+    InvalidReplay(ReplayError),
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoStorage => write!(f, "no storage is available for saves"),
+            Self::NotFound => write!(f, "no game was saved"),
+            Self::Storage(message) => write!(f, "save storage failed: {}", message),
+            Self::Format(message) => write!(f, "malformed save: {}", message),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported save version {}", version)
+            }
+            Self::MapSizeMismatch { options, game } => write!(
+                f,
+                "board options map size {:?} does not match the game map size {:?}",
+                options, game
+            ),
+            Self::MaxTileBombsMismatch { options, game } => write!(
+                f,
+                "board options let a tile hold {} bombs, the saved game {}",
+                options, game
+            ),
+            Self::GridMismatch => write!(
+                f,
+                "board options do not lay the tiles out like the saved game"
+            ),
+            Self::InvalidOptions(error) => write!(f, "invalid saved board options: {}", error),
+            Self::InvalidGame(error) => write!(f, "invalid saved game: {}", error),
+            Self::InvalidReplay(error) => write!(f, "invalid replay: {}", error),
+        }
+    }
+}
+
+impl Error for SaveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidOptions(error) => Some(error),
+            Self::InvalidGame(error) => Some(error),
+            Self::InvalidReplay(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<BoardOptionsError> for SaveError {
+    fn from(error: BoardOptionsError) -> Self {
+        Self::InvalidOptions(error)
+    }
+}
+
+impl From<ReplayError> for SaveError {
+    fn from(error: ReplayError) -> Self {
+        Self::InvalidReplay(error)
+    }
+}
+
+impl From<SnapshotError> for SaveError {
+    fn from(error: SnapshotError) -> Self {
+        Self::InvalidGame(error)
+    }
+}
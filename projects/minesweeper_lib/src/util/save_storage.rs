@@ -0,0 +1,57 @@
+#[cfg(feature = "wasm")]
+mod internal {
+    use crate::util::*;
+
+    fn local_storage() -> Result<web_sys::Storage, SaveError> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or(SaveError::NoStorage)
+    }
+
+    /// Writes a save to the browser local storage
+    pub fn write_save(name: &str, contents: &str) -> Result<(), SaveError> {
+        local_storage()?
+            .set_item(&format!("minesweeper.{}", name), contents)
+            .map_err(|error| SaveError::Storage(format!("{:?}", error)))
+    }
+
+    /// Reads a save from the browser local storage
+    pub fn read_save(name: &str) -> Result<String, SaveError> {
+        local_storage()?
+            .get_item(&format!("minesweeper.{}", name))
+            .map_err(|error| SaveError::Storage(format!("{:?}", error)))?
+            .ok_or(SaveError::NotFound)
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+mod internal {
+    use crate::util::*;
+    use std::{fs, io::ErrorKind, path::PathBuf};
+
+    fn save_path(name: &str) -> Result<PathBuf, SaveError> {
+        dirs::data_dir()
+            .map(|data_dir| data_dir.join("minesweeper").join(name))
+            .ok_or(SaveError::NoStorage)
+    }
+
+    /// Writes a save to a file in the user data directory
+    pub fn write_save(name: &str, contents: &str) -> Result<(), SaveError> {
+        let path = save_path(name)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| SaveError::Storage(error.to_string()))?;
+        }
+
+        fs::write(path, contents).map_err(|error| SaveError::Storage(error.to_string()))
+    }
+
+    /// Reads a save from a file in the user data directory
+    pub fn read_save(name: &str) -> Result<String, SaveError> {
+        fs::read_to_string(save_path(name)?).map_err(|error| match error.kind() {
+            ErrorKind::NotFound => SaveError::NotFound,
+            _ => SaveError::Storage(error.to_string()),
+        })
+    }
+}
+
+pub use internal::*;
@@ -0,0 +1,108 @@
+use crate::{resources::*, util::*};
+use minesweeper_core::GameSnapshot;
+use serde::{Deserialize, Serialize};
+
+/// A board of a saved game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedBoard {
+    /// The options the board was spawned with
+    pub options: BoardOptions,
+
+    /// The game played on the board
+    pub game: GameSnapshot,
+
+    /// What the player did on the board, nothing in saves which did not count it
+    #[serde(default)]
+    pub counters: BoardCounters,
+}
+
+impl SavedBoard {
+    pub fn new(board: &Board, board_options: &BoardOptions) -> Self {
+        Self {
+            options: board_options.clone(),
+            game: board.game.snapshot(),
+            counters: board.counters,
+        }
+    }
+
+    /// Restores the game played on the board, checking that the board options can be played and
+    /// that the game matches them
+    pub fn restore(&self) -> Result<Game, SaveError> {
+        self.options.validate()?;
+
+        let game_map_size = (self.game.width, self.game.height);
+        if self.options.map_size != game_map_size {
+            return Err(SaveError::MapSizeMismatch {
+                options: self.options.map_size,
+                game: game_map_size,
+            });
+        }
+
+        if self.options.max_tile_bombs != self.game.max_tile_bombs {
+            return Err(SaveError::MaxTileBombsMismatch {
+                options: self.options.max_tile_bombs,
+                game: self.game.max_tile_bombs,
+            });
+        }
+
+        if self.options.grid() != self.game.grid() {
+            return Err(SaveError::GridMismatch);
+        }
+
+        Ok(Game::from_snapshot(&self.game)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Coordinates;
+    use bevy::prelude::default;
+
+    fn saved_board(options_mask: Mask, game_mask: Mask) -> SavedBoard {
+        let options = BoardOptions {
+            bomb_count: 1,
+            ..default()
+        };
+        SavedBoard {
+            options: options.with_mask(options_mask),
+            game: Game::new(TileMap::new_with_bombs_at(
+                3,
+                2,
+                &[Coordinates { x: 0, y: 0 }],
+            ))
+            .with_mask(game_mask)
+            .snapshot(),
+            counters: default(),
+        }
+    }
+
+    #[test]
+    fn masked_boards_restore() {
+        let mask = Mask::from_ascii("###\n##.");
+        let game = saved_board(mask.clone(), mask).restore().unwrap();
+
+        assert_eq!(game.tile_map().grid().tile_count(), 5);
+    }
+
+    #[test]
+    fn masks_of_another_shape_are_rejected() {
+        let saved_board = saved_board(Mask::from_ascii("###\n##."), Mask::from_ascii("###\n.##"));
+        assert_eq!(saved_board.restore().unwrap_err(), SaveError::GridMismatch);
+    }
+
+    #[test]
+    fn masks_of_another_size_are_rejected() {
+        let mut saved_board =
+            saved_board(Mask::from_ascii("###\n###"), Mask::from_ascii("###\n###"));
+        saved_board.options.mask = Some(Mask::from_ascii("##\n##"));
+        assert_eq!(
+            saved_board.restore().unwrap_err(),
+            SaveError::InvalidOptions(BoardOptionsError::InvalidMask)
+        );
+
+        saved_board.options.mask = None;
+        saved_board.game.mask = Some(Mask::from_ascii("##\n##"));
+        assert_eq!(saved_board.restore().unwrap_err(), SaveError::GridMismatch);
+    }
+}
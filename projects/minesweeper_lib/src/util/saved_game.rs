@@ -0,0 +1,46 @@
+use crate::util::*;
+use serde::{Deserialize, Serialize};
+
+/// The version of the save format written by this build
+pub const SAVE_VERSION: u32 = 1;
+
+/// The name of the saved game in the save storage
+pub const SAVED_GAME_NAME: &str = "save.ron";
+
+/// A game in progress, saved as RON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedGame {
+    /// The version of the save format
+    pub version: u32,
+
+    /// The boards played
+    pub boards: Vec<SavedBoard>,
+
+    /// Seconds elapsed since the first tile was uncovered
+    pub elapsed: f32,
+}
+
+impl SavedGame {
+    pub fn new(boards: Vec<SavedBoard>, elapsed: f32) -> Self {
+        Self {
+            version: SAVE_VERSION,
+            boards,
+            elapsed,
+        }
+    }
+
+    pub fn to_ron(&self) -> Result<String, SaveError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|error| SaveError::Format(error.to_string()))
+    }
+
+    /// Reads a save, rejecting the versions of the format this build does not know
+    pub fn from_ron(ron: &str) -> Result<Self, SaveError> {
+        from_versioned_ron(ron, SAVE_VERSION)
+    }
+
+    /// Restores the games played on the boards, failing on the first invalid one
+    pub fn restore(&self) -> Result<Vec<Game>, SaveError> {
+        self.boards.iter().map(SavedBoard::restore).collect()
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{resources::*, util::*};
+use serde::{Deserialize, Serialize};
+
+/// The replay of a board
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedReplay {
+    /// The options the board was spawned with
+    pub options: BoardOptions,
+
+    /// The inputs made on the board
+    pub replay: Replay,
+}
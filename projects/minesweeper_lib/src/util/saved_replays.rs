@@ -0,0 +1,47 @@
+use crate::util::*;
+use serde::{Deserialize, Serialize};
+
+/// The version of the replay format written by this build
+pub const REPLAY_VERSION: u32 = 1;
+
+/// The name of the last replay in the save storage
+pub const SAVED_REPLAYS_NAME: &str = "replay.ron";
+
+/// The replays of the boards of a game, saved as compact RON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedReplays {
+    /// The version of the replay format
+    pub version: u32,
+
+    /// The replay of every board
+    pub replays: Vec<SavedReplay>,
+}
+
+impl SavedReplays {
+    pub fn new(replays: Vec<SavedReplay>) -> Self {
+        Self {
+            version: REPLAY_VERSION,
+            replays,
+        }
+    }
+
+    pub fn to_ron(&self) -> Result<String, SaveError> {
+        ron::to_string(self).map_err(|error| SaveError::Format(error.to_string()))
+    }
+
+    /// Reads replays, rejecting the versions of the format this build does not know
+    pub fn from_ron(ron: &str) -> Result<Self, SaveError> {
+        from_versioned_ron(ron, REPLAY_VERSION)
+    }
+
+    /// Checks that every replay has board options which can be played and reproduces the game it
+    /// was recorded from
+    pub fn verify(&self) -> Result<(), SaveError> {
+        for saved_replay in &self.replays {
+            saved_replay.options.validate()?;
+            saved_replay.replay.verify()?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{resources::*, util::*};
+use serde::{Deserialize, Serialize};
+
+/// The version of the statistics format written by this build
+pub const STATS_VERSION: u32 = 1;
+
+/// The name of the statistics in the save storage
+pub const SAVED_STATS_NAME: &str = "stats.ron";
+
+/// The statistics of every category, saved as RON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedStats {
+    /// The version of the statistics format
+    pub version: u32,
+
+    pub stats: GameStats,
+}
+
+impl SavedStats {
+    pub fn new(stats: GameStats) -> Self {
+        Self {
+            version: STATS_VERSION,
+            stats,
+        }
+    }
+
+    pub fn to_ron(&self) -> Result<String, SaveError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|error| SaveError::Format(error.to_string()))
+    }
+
+    /// Reads statistics, rejecting the versions of the format this build does not know
+    pub fn from_ron(ron: &str) -> Result<Self, SaveError> {
+        from_versioned_ron(ron, STATS_VERSION)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::resources::*;
+use bevy::prelude::*;
+
+/// Spawns the bomb sprite of a revealed tile holding `bomb_count` bombs, with a badge counting
+/// them when there are several, returning the bomb entity
+pub fn spawn_bomb(
+    parent: &mut ChildBuilder,
+    board_assets: &BoardAssets,
+    size: f32,
+    bomb_count: u8,
+) -> Entity {
+    let mut bomb = parent.spawn(board_assets.bomb_sprite_bundle(size));
+    if bomb_count > 1 {
+        bomb.with_children(|parent| {
+            parent
+                .spawn(board_assets.count_badge_text_bundle(bomb_count, size))
+                .insert(Name::new("Bomb Count"));
+        });
+    }
+    bomb.id()
+}
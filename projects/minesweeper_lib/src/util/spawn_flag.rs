@@ -0,0 +1,21 @@
+use crate::{components::*, resources::*};
+use bevy::prelude::*;
+
+/// Spawns the flag of a tile holding `flag_count` flags on its cover, with a badge counting them
+/// when there are several
+pub fn spawn_flag(
+    parent: &mut ChildBuilder,
+    board_assets: &BoardAssets,
+    size: f32,
+    flag_count: u8,
+) {
+    let mut flag = parent.spawn(board_assets.flag_sprite_bundle(size));
+    flag.insert(Name::new("Flag")).insert(Flag);
+    if flag_count > 1 {
+        flag.with_children(|parent| {
+            parent
+                .spawn(board_assets.count_badge_text_bundle(flag_count, size))
+                .insert(Name::new("Flag Count"));
+        });
+    }
+}
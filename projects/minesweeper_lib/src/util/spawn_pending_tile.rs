@@ -0,0 +1,34 @@
+use crate::{components::*, resources::*};
+use bevy::prelude::*;
+
+/// Spawns the sprite highlighting the tile a touch or a cursor is pending on, as a child of the
+/// board
+pub fn spawn_pending_tile(
+    commands: &mut Commands,
+    board: &Board,
+    board_assets: &BoardAssets,
+    coordinates: Coordinates,
+    z: f32,
+) -> Entity {
+    let pending_tile = commands
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                color: board_assets.pending_tile_material.color,
+                custom_size: Some(Vec2::splat(board.tile_size - board.tile_padding)),
+                ..default()
+            },
+            texture: board_assets.pending_tile_material.texture.clone(),
+            transform: Transform::from_translation(pending_tile_translation(board, coordinates, z)),
+            ..default()
+        })
+        .insert(Cover)
+        .id();
+    commands.entity(board.entity).push_children(&[pending_tile]);
+
+    pending_tile
+}
+
+/// Returns the position of the highlight of a tile in the board
+pub fn pending_tile_translation(board: &Board, coordinates: Coordinates, z: f32) -> Vec3 {
+    board.tile_translation(coordinates).extend(z)
+}
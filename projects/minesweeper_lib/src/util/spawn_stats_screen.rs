@@ -0,0 +1,64 @@
+use crate::{components::*, resources::*};
+use bevy::prelude::*;
+
+/// Spawns the statistics screen, its text is filled by `update_stats_screen`
+pub fn spawn_stats_screen(
+    commands: &mut Commands,
+    colors: &BoardColors,
+    board_assets: &BoardAssets,
+) {
+    let text_style = |font_size: f32, color: Color| TextStyle {
+        font: board_assets.bomb_number_font.clone(),
+        font_size,
+        color,
+    };
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                padding: UiRect::all(Val::Px(20.)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.),
+                ..default()
+            },
+            background_color: colors.padding_color.into(),
+            ..default()
+        })
+        .insert(Name::new("Statistics Screen"))
+        .insert(StatsScreen)
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Statistics",
+                text_style(30., colors.flag_color),
+            ));
+
+            parent
+                .spawn(TextBundle::from_section(
+                    "",
+                    text_style(16., colors.bomb_color),
+                ))
+                .insert(Name::new("Statistics"))
+                .insert(StatsScreenText);
+
+            parent
+                .spawn(ButtonBundle {
+                    style: Style {
+                        padding: UiRect::all(Val::Px(10.)),
+                        align_self: AlignSelf::Start,
+                        ..default()
+                    },
+                    background_color: colors.unknown_tile_color.into(),
+                    ..default()
+                })
+                .insert(Name::new("Reset Statistics Button"))
+                .insert(StatsResetButton)
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Reset",
+                        text_style(20., board_assets.bomb_number_color(1)),
+                    ));
+                });
+        });
+}
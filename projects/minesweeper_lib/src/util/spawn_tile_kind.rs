@@ -0,0 +1,35 @@
+use crate::{components::*, resources::*, util::*};
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+/// Inserts the components matching `tile` on a tile root entity and spawns its bomb sprite or bomb
+/// counter text, returning the spawned child
+pub fn spawn_tile_kind(
+    entity_commands: &mut EntityCommands,
+    tile: &Tile,
+    board_assets: &BoardAssets,
+    size: f32,
+) -> Option<Entity> {
+    let mut kind_id = None;
+    match tile {
+        Tile::Bomb(count) => {
+            entity_commands.insert(Bomb);
+            entity_commands.with_children(|parent| {
+                kind_id = Some(spawn_bomb(parent, board_assets, size, *count));
+            });
+        }
+
+        Tile::BombNeighbor(v) => {
+            entity_commands.insert(BombNeighbor { number: *v });
+            entity_commands.with_children(|parent| {
+                kind_id = Some(
+                    parent
+                        .spawn(board_assets.bomb_count_text_bundle(*v, size))
+                        .id(),
+                );
+            });
+        }
+
+        Tile::Empty | Tile::Void => (),
+    };
+    kind_id
+}
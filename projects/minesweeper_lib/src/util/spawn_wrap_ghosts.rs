@@ -0,0 +1,50 @@
+use crate::{components::*, resources::*};
+use bevy::prelude::*;
+
+/// Spawns the copies of the tiles along the wrapped edges of a board beyond the opposite edges,
+/// corners included when both axes wrap. They are drawn by `update_wrap_ghosts`.
+pub fn spawn_wrap_ghosts(
+    commands: &mut Commands,
+    board: Entity,
+    tile_map: &TileMap,
+) -> Vec<Entity> {
+    let grid = tile_map.grid();
+    let shifts = |wraps: bool| if wraps { vec![-1, 0, 1] } else { vec![0] };
+    // The copies beyond an edge are the tiles along the opposite edge
+    let sources = |shift: i32, size: u16| match shift {
+        -1 => size - 1..size,
+        1 => 0..1,
+        _ => 0..size,
+    };
+
+    let mut ghosts = Vec::new();
+    for shift_y in shifts(grid.wraps_vertically()) {
+        for shift_x in shifts(grid.wraps_horizontally()) {
+            if (shift_x, shift_y) == (0, 0) {
+                continue;
+            }
+
+            for y in sources(shift_y, grid.height) {
+                for x in sources(shift_x, grid.width) {
+                    let coordinates = Coordinates { x, y };
+                    if !grid.in_bounds(coordinates) {
+                        continue;
+                    }
+                    let ghost = commands
+                        .spawn(SpriteBundle::default())
+                        .insert(Name::new(format!("Wrap Ghost ({}, {})", x, y)))
+                        .insert(WrapGhost {
+                            board,
+                            coordinates,
+                            shift: Vec2::new(shift_x as f32, shift_y as f32),
+                            shown: None,
+                        })
+                        .id();
+                    ghosts.push(ghost);
+                }
+            }
+        }
+    }
+
+    ghosts
+}
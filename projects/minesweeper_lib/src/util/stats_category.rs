@@ -0,0 +1,45 @@
+use crate::{config::Difficulty, resources::*, util::Grid};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// The games whose statistics are kept together: the games of a difficulty, played with or
+/// without assistance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatsCategory {
+    pub difficulty: Difficulty,
+
+    /// Were hints, the probability overlay or the practice mode used
+    pub assisted: bool,
+}
+
+impl StatsCategory {
+    /// Returns the category of the game played on a board. The presets are played on square grids
+    /// with one bomb per tile, any other grid or bomb rule makes a custom game.
+    pub fn of(board: &Board, board_options: &BoardOptions) -> Self {
+        let (width, height) = board_options.map_size;
+        let standard =
+            board_options.grid() == Grid::new(width, height) && board_options.max_tile_bombs == 1;
+        let difficulty = if standard {
+            Difficulty::of(board_options.map_size, board_options.bomb_count)
+        } else {
+            Difficulty::Custom
+        };
+
+        Self {
+            difficulty,
+            assisted: board.counters.hints_used > 0
+                || board.counters.probabilities_shown
+                || board_options.practice,
+        }
+    }
+}
+
+impl Display for StatsCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.assisted {
+            write!(f, "{:?} (assisted)", self.difficulty)
+        } else {
+            write!(f, "{:?}", self.difficulty)
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{components::*, resources::*, util::*};
+use bevy::prelude::*;
+
+/// A cursor over the tiles of a board, shown by the pending tile highlight
+#[derive(Debug, Clone, Copy)]
+pub struct TileCursor {
+    pub board: Entity,
+    pub coordinates: Coordinates,
+    pub highlight: Entity,
+}
+
+impl TileCursor {
+    /// Shows a cursor on a tile of a board
+    pub fn spawn(
+        commands: &mut Commands,
+        board: &Board,
+        board_assets: &BoardAssets,
+        coordinates: Coordinates,
+    ) -> Self {
+        let highlight = spawn_pending_tile(
+            commands,
+            board,
+            board_assets,
+            coordinates,
+            Self::z(board, coordinates),
+        );
+
+        Self {
+            board: board.entity,
+            coordinates,
+            highlight,
+        }
+    }
+
+    /// Moves the cursor by a number of tiles on each axis, on to the next tile past void cells.
+    /// The cursor stays in place when there is none.
+    pub fn move_by(
+        &mut self,
+        board: &Board,
+        board_options: &BoardOptions,
+        (x, y): (i32, i32),
+        transforms: &mut Query<&mut Transform, With<Cover>>,
+    ) {
+        let tile_map = board.game.tile_map();
+        // The cursor crosses the wrapped edges like the neighbors do
+        let edge = |wraps| {
+            if wraps {
+                CursorEdgeOption::Wrap
+            } else {
+                board_options.cursor_edge
+            }
+        };
+        let grid = tile_map.grid();
+        let step = |coordinates: Coordinates| Coordinates {
+            x: edge(grid.wraps_horizontally()).apply(coordinates.x, x, grid.width),
+            y: edge(grid.wraps_vertically()).apply(coordinates.y, y, grid.height),
+        };
+
+        let mut coordinates = step(self.coordinates);
+        for _ in 0..grid.width.max(grid.height) {
+            let next = step(coordinates);
+            if grid.in_bounds(coordinates) || next == coordinates {
+                break;
+            }
+            coordinates = next;
+        }
+        if grid.in_bounds(coordinates) {
+            self.coordinates = coordinates;
+        }
+        self.place(board, transforms);
+    }
+
+    /// Moves the highlight to the tile of the cursor, over or under the cover of the tile
+    pub fn place(&self, board: &Board, transforms: &mut Query<&mut Transform, With<Cover>>) {
+        if let Ok(mut transform) = transforms.get_mut(self.highlight) {
+            transform.translation =
+                pending_tile_translation(board, self.coordinates, Self::z(board, self.coordinates));
+        }
+    }
+
+    /// Covered tiles are highlighted over their cover, revealed ones under their number
+    fn z(board: &Board, coordinates: Coordinates) -> f32 {
+        if board.covered_tiles.contains_key(&coordinates) {
+            3.5
+        } else {
+            1.5
+        }
+    }
+}
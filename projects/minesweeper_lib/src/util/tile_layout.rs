@@ -0,0 +1,136 @@
+use crate::components::Coordinates;
+use bevy::{math::U16Vec2, prelude::*};
+use minesweeper_core::Topology;
+
+/// Where the tiles of a topology are drawn. The tile size is the distance between two rows, hex
+/// tiles are further apart on a row so that the distance between all neighbors is the same.
+pub trait TileLayout {
+    /// Returns the distance between two tiles of a row and between two rows, in tile sizes
+    fn tile_step(&self) -> Vec2;
+
+    /// Returns the size of a tile map in tile sizes
+    fn size_in_tiles(&self, map_size: U16Vec2) -> Vec2;
+
+    /// Returns the position of the center of a tile, from the bottom left corner of the board
+    fn tile_translation(&self, coordinates: Coordinates, tile_size: f32) -> Vec2;
+
+    /// Returns the coordinates of the tile at a position from the bottom left corner of the board,
+    /// if any
+    fn tile_at(&self, position: Vec2, tile_size: f32, map_size: U16Vec2) -> Option<Coordinates>;
+}
+
+impl TileLayout for Topology {
+    fn tile_step(&self) -> Vec2 {
+        match self {
+            Topology::Square => Vec2::ONE,
+            Topology::Hex => Vec2::new(HEX_COLUMN_WIDTH, 1.),
+        }
+    }
+
+    fn size_in_tiles(&self, map_size: U16Vec2) -> Vec2 {
+        let (width, height) = (map_size.x as f32, map_size.y as f32);
+        match self {
+            Topology::Square => Vec2::new(width, height),
+            Topology::Hex => {
+                // The odd rows stick out by half a tile
+                let width = if map_size.y > 1 { width + 0.5 } else { width };
+                Vec2::new(width * HEX_COLUMN_WIDTH, height)
+            }
+        }
+    }
+
+    fn tile_translation(&self, coordinates: Coordinates, tile_size: f32) -> Vec2 {
+        let (x, y) = (coordinates.x as f32, coordinates.y as f32);
+        match self {
+            Topology::Square => Vec2::new(x + 0.5, y + 0.5) * tile_size,
+            Topology::Hex => {
+                let shift = (coordinates.y % 2) as f32 / 2.;
+                Vec2::new((x + 0.5 + shift) * HEX_COLUMN_WIDTH, y + 0.5) * tile_size
+            }
+        }
+    }
+
+    fn tile_at(&self, position: Vec2, tile_size: f32, map_size: U16Vec2) -> Option<Coordinates> {
+        let position = position / tile_size;
+        let (x, y) = match self {
+            Topology::Square => (position.x.floor() as i32, position.y.floor() as i32),
+            Topology::Hex => {
+                // Tiles are drawn as squares, a row of them is a row of the board and the space
+                // between two tiles of a row goes to the closest one, like the padding of square
+                // tiles
+                let y = position.y.floor();
+                let shift = (y as i32).rem_euclid(2) as f32 / 2.;
+                let x = (position.x / HEX_COLUMN_WIDTH - shift).floor();
+                (x as i32, y as i32)
+            }
+        };
+
+        let in_bounds = (0..map_size.x as i32).contains(&x) && (0..map_size.y as i32).contains(&y);
+        in_bounds.then_some(Coordinates {
+            x: x as u16,
+            y: y as u16,
+        })
+    }
+}
+
+/// The distance between two hex tiles of a row, in tile sizes
+const HEX_COLUMN_WIDTH: f32 = 2. / SQRT_3;
+
+const SQRT_3: f32 = 1.732_050_8;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP_SIZE: U16Vec2 = U16Vec2::new(5, 5);
+
+    #[test]
+    fn tile_centers_hit_their_tile() {
+        for topology in [Topology::Square, Topology::Hex] {
+            for y in 0..MAP_SIZE.y {
+                for x in 0..MAP_SIZE.x {
+                    let coordinates = Coordinates { x, y };
+                    let center = topology.tile_translation(coordinates, 10.);
+                    assert_eq!(topology.tile_at(center, 10., MAP_SIZE), Some(coordinates));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hex_corners_hit_the_square_drawn_there() {
+        // The top right corner of the square of (1, 0) is closer to the center of (1, 1), the
+        // tile drawn there is still (1, 0)
+        let position = Vec2::new(2.2, 0.95);
+        assert!(
+            position.distance(Topology::Hex.tile_translation(Coordinates { x: 1, y: 1 }, 1.))
+                < position.distance(Topology::Hex.tile_translation(Coordinates { x: 1, y: 0 }, 1.))
+        );
+        assert_eq!(
+            Topology::Hex.tile_at(position, 1., MAP_SIZE),
+            Some(Coordinates { x: 1, y: 0 })
+        );
+
+        // The bottom left corner of the shifted square of (0, 1)
+        assert_eq!(
+            Topology::Hex.tile_at(Vec2::new(0.7, 1.05), 1., MAP_SIZE),
+            Some(Coordinates { x: 0, y: 1 })
+        );
+    }
+
+    #[test]
+    fn positions_off_the_board_hit_nothing() {
+        assert_eq!(
+            Topology::Square.tile_at(Vec2::new(-1., 2.), 1., MAP_SIZE),
+            None
+        );
+        assert_eq!(
+            Topology::Hex.tile_at(Vec2::new(0.2, 1.5), 1., MAP_SIZE),
+            None
+        );
+        assert_eq!(
+            Topology::Hex.tile_at(Vec2::new(2., 5.5), 1., MAP_SIZE),
+            None
+        );
+    }
+}